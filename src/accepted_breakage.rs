@@ -0,0 +1,357 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use trustfall::FieldValue;
+
+use crate::{LintLevel, Report};
+
+/// Lint outputs naming the part of an item that a lint flagged, like the method of a type.
+///
+/// All that are present are appended to the item's path, in this order.
+/// For example, a field of an enum variant is identified as `my_crate::MyEnum::Variant::field`.
+const MEMBER_OUTPUTS: &[&str] = &[
+    "variant_name",
+    "field_name",
+    "method_name",
+    "method",
+    "associated_type",
+    "associated_constant",
+    "feature",
+];
+
+/// Lint outputs naming a trait implemented by the flagged item, like a removed trait impl.
+///
+/// Checked in order. The first one present identifies the result as `<my_crate::MyType as Trait>`.
+const IMPLEMENTED_TRAIT_OUTPUTS: &[&str] = &["trait_path", "auto_trait_path", "implemented_trait"];
+
+const FILE_HEADER: &str = "\
+# Breaking changes that cargo-semver-checks should not report.
+#
+# Each entry names a lint and the path of an item that lint flagged.
+# Entries may also have a `reason` explaining why the change is accepted.
+";
+
+/// Lint results that are known and deliberately accepted, so they should not be reported.
+///
+/// Unlike lint-level configuration, this applies to individual items:
+/// each entry names a lint together with the path of an item that lint flagged.
+/// It's read from and written to TOML files such as `semver-checks-baseline.toml`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AcceptedBreakage {
+    #[serde(default, rename = "accepted")]
+    entries: Vec<AcceptedEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AcceptedEntry {
+    /// The id of the lint whose result is accepted.
+    lint: String,
+    /// The path of the flagged item, as produced by [`item_path`].
+    path: String,
+    /// Why the breakage is accepted. Not interpreted, but kept when the file is rewritten.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+}
+
+impl AcceptedBreakage {
+    /// Reads the accepted breakage listed in the TOML file at `path`.
+    pub fn from_path(path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    /// Writes the accepted breakage to a TOML file at `path`, replacing any existing file.
+    pub fn write_to(&self, path: &Path) -> anyhow::Result<()> {
+        let contents = toml::to_string(self).context("failed to serialize accepted breakage")?;
+        std::fs::write(path, format!("{FILE_HEADER}\n{contents}"))
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    /// Replaces the accepted breakage with every result in the report that fails the check.
+    ///
+    /// Entries for lints that can't fail the check, like ones configured to warn,
+    /// are kept as they are. Reasons given for previously-accepted results are kept.
+    /// Results that don't identify the item they flagged can't be accepted and are left out.
+    pub fn update_from_report(&mut self, report: &Report) {
+        let denied = |lint: &str| {
+            report.crate_reports().values().any(|crate_report| {
+                crate_report.lint_levels.get(lint).copied() == Some(LintLevel::Deny)
+            })
+        };
+        let (previous, kept): (Vec<_>, Vec<_>) = std::mem::take(&mut self.entries)
+            .into_iter()
+            .partition(|entry| denied(&entry.lint));
+        let mut reasons: BTreeMap<(String, String), String> = previous
+            .into_iter()
+            .filter_map(|entry| Some(((entry.lint, entry.path), entry.reason?)))
+            .collect();
+
+        let accepted: BTreeSet<(String, String)> = report
+            .crate_reports()
+            .values()
+            .flat_map(|crate_report| crate_report.findings())
+            // Only findings that fail the check need to be accepted.
+            .filter(|finding| finding.lint_level() == LintLevel::Deny)
+            .flat_map(|finding| {
+                let lint = &finding.query().id;
+                finding.results().iter().filter_map(|result| {
+                    let path = item_path(result.values())?;
+                    Some((lint.clone(), path))
+                })
+            })
+            .collect();
+        let mut entries: Vec<_> = accepted
+            .into_iter()
+            .map(|key| {
+                let reason = reasons.remove(&key);
+                let (lint, path) = key;
+                AcceptedEntry { lint, path, reason }
+            })
+            .chain(kept)
+            .collect();
+        entries.sort_by(|a, b| (&a.lint, &a.path).cmp(&(&b.lint, &b.path)));
+
+        self.entries = entries;
+    }
+
+    /// The number of accepted lint results.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// `true` if no lint results are accepted.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Whether the result of the given lint has been accepted, and should not be reported.
+    pub(crate) fn accepts(&self, lint_id: &str, values: &BTreeMap<Arc<str>, FieldValue>) -> bool {
+        let Some(path) = item_path(values) else {
+            return false;
+        };
+        self.entries
+            .iter()
+            .any(|entry| entry.lint == lint_id && entry.path == path)
+    }
+}
+
+/// Identifies the item flagged by a lint result, like `my_crate::MyStruct::my_method`.
+///
+/// Built from the result's `path` output (or `name`, if it has no path),
+/// followed by the names of the flagged members of that item, if any.
+/// Results about a trait impl are identified as `<my_crate::MyStruct as Trait>`.
+pub(crate) fn item_path(values: &BTreeMap<Arc<str>, FieldValue>) -> Option<String> {
+    let item = values
+        .get("path")
+        .and_then(path_value)
+        .or_else(|| values.get("name").and_then(path_value));
+    let item = match IMPLEMENTED_TRAIT_OUTPUTS
+        .iter()
+        .find_map(|output| values.get(*output).and_then(path_value))
+    {
+        Some(implemented_trait) => Some(format!("<{} as {implemented_trait}>", item?)),
+        None => item,
+    };
+
+    item.into_iter()
+        .chain(
+            MEMBER_OUTPUTS
+                .iter()
+                .filter_map(|output| values.get(*output).and_then(path_value)),
+        )
+        .reduce(|path, member| format!("{path}::{member}"))
+}
+
+/// Formats a lint output naming an item, given either as a name or as a list of path components.
+fn path_value(value: &FieldValue) -> Option<String> {
+    value.as_str().map(ToString::to_string).or_else(|| {
+        value
+            .as_vec_with(FieldValue::as_str)
+            .map(|path| path.join("::"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+
+    use trustfall::FieldValue;
+
+    use super::{AcceptedBreakage, AcceptedEntry, item_path};
+    use crate::query::{ActualSemverUpdate, LintLevel, RequiredSemverUpdate, SemverQuery};
+    use crate::{CrateReport, LintFinding, LintResult, Report};
+
+    fn values(outputs: &[(&str, FieldValue)]) -> BTreeMap<Arc<str>, FieldValue> {
+        outputs
+            .iter()
+            .map(|(name, value)| (Arc::from(*name), value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn item_path_from_lint_outputs() {
+        let path: FieldValue = vec!["my_crate", "MyStruct"].into();
+        assert_eq!(
+            item_path(&values(&[
+                ("path", path.clone()),
+                ("name", "MyStruct".into())
+            ]))
+            .as_deref(),
+            Some("my_crate::MyStruct"),
+        );
+        assert_eq!(
+            item_path(&values(&[("path", path), ("method_name", "method".into())])).as_deref(),
+            Some("my_crate::MyStruct::method"),
+        );
+        assert_eq!(
+            item_path(&values(&[("name", "my_fn".into())])).as_deref(),
+            Some("my_fn"),
+        );
+        assert_eq!(
+            item_path(&values(&[("feature", "std".into())])).as_deref(),
+            Some("std"),
+        );
+        assert_eq!(
+            item_path(&values(&[("span_begin_line", 3u64.into())])),
+            None
+        );
+    }
+
+    #[test]
+    fn item_path_of_variant_fields_includes_variant() {
+        let path: FieldValue = vec!["my_crate", "MyEnum"].into();
+        let in_variant = |variant: &str| {
+            item_path(&values(&[
+                ("path", path.clone()),
+                ("variant_name", variant.into()),
+                ("field_name", "x".into()),
+            ]))
+        };
+        assert_eq!(
+            in_variant("First").as_deref(),
+            Some("my_crate::MyEnum::First::x")
+        );
+        assert_ne!(in_variant("First"), in_variant("Second"));
+    }
+
+    #[test]
+    fn item_path_of_trait_impls_includes_trait() {
+        let path: FieldValue = vec!["my_crate", "MyStruct"].into();
+        let derived = |trait_path: Vec<&str>| {
+            item_path(&values(&[
+                ("path", path.clone()),
+                ("trait_name", trait_path[trait_path.len() - 1].into()),
+                ("trait_path", trait_path.into()),
+            ]))
+        };
+        assert_eq!(
+            derived(vec!["core", "fmt", "Debug"]).as_deref(),
+            Some("<my_crate::MyStruct as core::fmt::Debug>")
+        );
+        assert_ne!(
+            derived(vec!["core", "fmt", "Debug"]),
+            derived(vec!["core", "clone", "Clone"])
+        );
+
+        let implemented = |implemented_trait: &str| {
+            item_path(&values(&[
+                ("path", path.clone()),
+                ("implemented_trait", implemented_trait.into()),
+            ]))
+        };
+        assert_eq!(
+            implemented("From<u8>").as_deref(),
+            Some("<my_crate::MyStruct as From<u8>>")
+        );
+        assert_ne!(implemented("From<u8>"), implemented("From<u16>"));
+    }
+
+    #[test]
+    fn parse_accepted_breakage() {
+        let accepted: AcceptedBreakage = toml::from_str(
+            r#"
+            [[accepted]]
+            lint = "function_missing"
+            path = "my_crate::removed_fn"
+            reason = "nobody used it"
+            "#,
+        )
+        .expect("valid accepted breakage");
+        assert_eq!(accepted.len(), 1);
+
+        let path: FieldValue = vec!["my_crate", "removed_fn"].into();
+        let result = values(&[("path", path)]);
+        assert!(accepted.accepts("function_missing", &result));
+        assert!(!accepted.accepts("function_now_doc_hidden", &result));
+
+        toml::from_str::<AcceptedBreakage>(
+            r#"
+            [[accepted]]
+            lint = "function_missing"
+            "#,
+        )
+        .expect_err("entries without a path should be invalid");
+    }
+
+    #[test]
+    fn update_from_report_keeps_entries_of_lints_that_dont_deny() {
+        let entry = |lint: &str, path: &str, reason: Option<&str>| AcceptedEntry {
+            lint: lint.into(),
+            path: path.into(),
+            reason: reason.map(Into::into),
+        };
+        let mut accepted = AcceptedBreakage {
+            entries: vec![
+                entry("function_missing", "my_crate::stale_fn", None),
+                entry("function_missing", "my_crate::removed_fn", Some("unused")),
+                entry("trait_missing", "my_crate::RemovedTrait", Some("sealed")),
+            ],
+        };
+
+        let path: FieldValue = vec!["my_crate", "removed_fn"].into();
+        let crate_report = CrateReport {
+            detected_bump: ActualSemverUpdate::Major,
+            required_bump: None,
+            baseline_version: None,
+            current_version: None,
+            findings: vec![LintFinding {
+                query: SemverQuery::all_queries()["function_missing"].clone(),
+                lint_level: LintLevel::Deny,
+                required_update: RequiredSemverUpdate::Major,
+                results: vec![LintResult {
+                    values: values(&[("path", path)]),
+                    message: None,
+                    allowed_by: None,
+                }],
+            }],
+            passed_lints: vec![],
+            skipped_lints: vec![],
+            lint_levels: [
+                ("function_missing".to_string(), LintLevel::Deny),
+                ("trait_missing".to_string(), LintLevel::Warn),
+            ]
+            .into(),
+            lint_durations: BTreeMap::new(),
+        };
+        let report = Report {
+            crate_reports: [("my_crate".to_string(), crate_report)].into(),
+        };
+
+        accepted.update_from_report(&report);
+        assert_eq!(
+            accepted.entries,
+            vec![
+                entry("function_missing", "my_crate::removed_fn", Some("unused")),
+                entry("trait_missing", "my_crate::RemovedTrait", Some("sealed")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use trustfall::FieldValue;
+use trustfall_rustdoc::VersionedRustdocAdapter;
+
+use crate::accepted_breakage::item_path;
+
+/// The text that marks an item as exempt from some lints, when placed in its documentation.
+///
+/// For example: `/// semver-checks: allow(enum_variant_added)`
+pub(crate) const ALLOW_MARKER: &str = "semver-checks: allow(";
+
+/// Finds items of the given kind in the given crate (`current` or `baseline`)
+/// whose docs contain `$marker`, together with the paths they can be imported by.
+const MARKED_ITEMS_QUERY: &str = r#"
+{
+    CrateDiff {
+        CRATE {
+            item {
+                ... on KIND {
+                    docs @filter(op: "has_substring", value: ["$marker"]) @output
+
+                    importable_path {
+                        path @output
+                    }
+                }
+            }
+        }
+    }
+}"#;
+
+/// The kinds of items that have an importable path.
+///
+/// The schema's `Importable` interface is not an `Item`, so it can't be queried directly.
+const IMPORTABLE_KINDS: &[&str] = &[
+    "Module",
+    "Struct",
+    "Enum",
+    "Union",
+    "Trait",
+    "Function",
+    "GlobalValue",
+    "Macro",
+    "ProcMacro",
+];
+
+/// Finds methods of types and traits in the given crate (`current` or `baseline`)
+/// whose docs contain `$marker`. Methods can't be imported,
+/// so they are identified by the path of their type or trait.
+const MARKED_METHODS_QUERY: &str = r#"
+{
+    CrateDiff {
+        CRATE {
+            item {
+                ... on ImplOwner {
+                    importable_path {
+                        path @output
+                    }
+
+                    inherent_impl {
+                        method {
+                            docs @filter(op: "has_substring", value: ["$marker"]) @output
+                            method_name: name @output
+                        }
+                    }
+                }
+            }
+        }
+    }
+}"#;
+
+/// Like [`MARKED_METHODS_QUERY`], but for methods declared in traits.
+const MARKED_TRAIT_METHODS_QUERY: &str = r#"
+{
+    CrateDiff {
+        CRATE {
+            item {
+                ... on Trait {
+                    importable_path {
+                        path @output
+                    }
+
+                    method {
+                        docs @filter(op: "has_substring", value: ["$marker"]) @output
+                        method_name: name @output
+                    }
+                }
+            }
+        }
+    }
+}"#;
+
+/// An item that is exempt from some lints.
+#[derive(Debug, Default)]
+struct MarkedItem {
+    /// Whether results about the item's fields, variants, and methods are exempt too.
+    /// Not the case for modules, whose members are separate items.
+    covers_members: bool,
+    lints: BTreeSet<String>,
+}
+
+/// Items marked as exempt from specific lints via [`ALLOW_MARKER`] in their docs,
+/// in either the baseline or the current version of the crate.
+///
+/// A lint result is allowed if the item it flagged, as identified by [`item_path`],
+/// is such an item or a field, variant, or method of one.
+/// Results about a trait impl are allowed if the implementing type is marked.
+#[derive(Debug, Default)]
+pub(crate) struct AllowMarkers {
+    /// Marked items, by their path.
+    items: BTreeMap<String, MarkedItem>,
+}
+
+impl AllowMarkers {
+    pub(crate) fn collect(adapter: &VersionedRustdocAdapter<'_>) -> anyhow::Result<Self> {
+        let mut markers = Self::default();
+        let arguments = BTreeMap::from([("marker", ALLOW_MARKER)]);
+        for crate_edge in ["current", "baseline"] {
+            for kind in IMPORTABLE_KINDS {
+                let query = MARKED_ITEMS_QUERY
+                    .replace("CRATE", crate_edge)
+                    .replace("KIND", kind);
+                for result in adapter.run_query(&query, arguments.clone())? {
+                    let (Some(docs), Some(path)) = (
+                        result["docs"].as_str(),
+                        result["path"].as_vec_with(FieldValue::as_str),
+                    ) else {
+                        continue;
+                    };
+                    markers.insert(path.join("::"), *kind != "Module", docs);
+                }
+            }
+
+            for query in [MARKED_METHODS_QUERY, MARKED_TRAIT_METHODS_QUERY] {
+                let query = query.replace("CRATE", crate_edge);
+                for result in adapter.run_query(&query, arguments.clone())? {
+                    let (Some(docs), Some(path), Some(method_name)) = (
+                        result["docs"].as_str(),
+                        result["path"].as_vec_with(FieldValue::as_str),
+                        result["method_name"].as_str(),
+                    ) else {
+                        continue;
+                    };
+                    markers.insert(format!("{}::{method_name}", path.join("::")), true, docs);
+                }
+            }
+        }
+
+        Ok(markers)
+    }
+
+    fn insert(&mut self, path: String, covers_members: bool, docs: &str) {
+        let item = self.items.entry(path).or_default();
+        item.covers_members |= covers_members;
+        item.lints
+            .extend(allowed_lints(docs).map(ToString::to_string));
+    }
+
+    /// Whether the lint result flagged an item that is marked as exempt from that lint,
+    /// or a member of such an item.
+    pub(crate) fn allows(&self, lint_id: &str, values: &BTreeMap<Arc<str>, FieldValue>) -> bool {
+        let Some(path) = item_path(values) else {
+            return false;
+        };
+        let allowed_on = |path: &str, member: bool| {
+            self.items.get(path).is_some_and(|item| {
+                (item.covers_members || !member) && item.lints.contains(lint_id)
+            })
+        };
+
+        // Results about a trait impl are keyed like `<my_crate::Foo as Trait>`,
+        // so the implementing type is looked up from the `path` output instead.
+        let owner = values
+            .get("path")
+            .and_then(|path| path.as_vec_with(FieldValue::as_str))
+            .map(|path| path.join("::"));
+
+        allowed_on(&path, false)
+            || parents(&path).any(|parent| allowed_on(parent, true))
+            || owner.is_some_and(|owner| {
+                std::iter::once(owner.as_str())
+                    .chain(parents(&owner))
+                    .any(|owner| allowed_on(owner, true))
+            })
+    }
+}
+
+/// The paths of the items enclosing the given path, innermost first.
+fn parents(path: &str) -> impl Iterator<Item = &str> {
+    std::iter::successors(path.rsplit_once("::"), |(parent, _)| {
+        parent.rsplit_once("::")
+    })
+    .map(|(parent, _)| parent)
+}
+
+/// Parses the lint ids listed in each `semver-checks: allow(lint_a, lint_b)` marker in the text.
+fn allowed_lints(text: &str) -> impl Iterator<Item = &str> {
+    text.match_indices(ALLOW_MARKER).flat_map(|(start, _)| {
+        let rest = &text[start + ALLOW_MARKER.len()..];
+        let list = rest.split_once(')').map_or("", |(list, _)| list);
+        list.split(',')
+            .map(str::trim)
+            .filter(|lint| !lint.is_empty())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+
+    use trustfall::FieldValue;
+
+    use super::{AllowMarkers, allowed_lints};
+
+    fn values(outputs: &[(&str, FieldValue)]) -> BTreeMap<Arc<str>, FieldValue> {
+        outputs
+            .iter()
+            .map(|(name, value)| (Arc::from(*name), value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn allows_results_about_marked_items_and_their_members() {
+        let mut markers = AllowMarkers::default();
+        markers.insert(
+            "my_crate".into(),
+            false,
+            "semver-checks: allow(enum_variant_added)",
+        );
+        markers.insert(
+            "my_crate::AllowedEnum".into(),
+            true,
+            "semver-checks: allow(enum_variant_added)",
+        );
+
+        let variant_added = |path: Vec<&str>| {
+            values(&[
+                ("path", path.into()),
+                ("variant_name", "Second".into()),
+                ("span_filename", "src/lib.rs".into()),
+                ("span_begin_line", 7u64.into()),
+            ])
+        };
+        assert!(markers.allows(
+            "enum_variant_added",
+            &variant_added(vec!["my_crate", "AllowedEnum"])
+        ));
+        assert!(!markers.allows(
+            "enum_variant_missing",
+            &variant_added(vec!["my_crate", "AllowedEnum"])
+        ));
+        // Results about other items are not allowed, even if they are on the same lines
+        // or inside a marked module.
+        assert!(!markers.allows(
+            "enum_variant_added",
+            &variant_added(vec!["my_crate", "Enum"])
+        ));
+        assert!(!markers.allows(
+            "enum_variant_added",
+            &variant_added(vec!["my_crate", "AllowedEnumeration"])
+        ));
+    }
+
+    #[test]
+    fn allows_trait_impl_results_about_marked_types() {
+        let mut markers = AllowMarkers::default();
+        markers.insert(
+            "my_crate::AllowedStruct".into(),
+            true,
+            "semver-checks: allow(trait_impl_removed)",
+        );
+
+        let trait_impl_removed = |path: Vec<&str>| {
+            values(&[
+                ("path", path.into()),
+                ("implemented_trait", "core::fmt::Debug".into()),
+                ("span_filename", "src/lib.rs".into()),
+                ("span_begin_line", 3u64.into()),
+            ])
+        };
+        assert!(markers.allows(
+            "trait_impl_removed",
+            &trait_impl_removed(vec!["my_crate", "AllowedStruct"])
+        ));
+        assert!(!markers.allows(
+            "auto_trait_impl_removed",
+            &trait_impl_removed(vec!["my_crate", "AllowedStruct"])
+        ));
+        assert!(!markers.allows(
+            "trait_impl_removed",
+            &trait_impl_removed(vec!["my_crate", "Struct"])
+        ));
+    }
+
+    #[test]
+    fn parse_allowed_lints() {
+        let docs = "Some docs.\n\n\
+            semver-checks: allow(enum_variant_added)\n\
+            More docs. semver-checks: allow( function_missing, trait_missing )";
+        assert_eq!(
+            allowed_lints(docs).collect::<Vec<_>>(),
+            ["enum_variant_added", "function_missing", "trait_missing"],
+        );
+
+        // Unterminated markers don't allow anything.
+        assert_eq!(
+            allowed_lints("semver-checks: allow(function_missing").count(),
+            0
+        );
+    }
+}
@@ -10,10 +10,12 @@ use itertools::Itertools;
 use rayon::prelude::*;
 use trustfall::{FieldValue, TransparentValue};
 
+use crate::allow_markers::{ALLOW_MARKER, AllowMarkers};
 use crate::data_generation::DataStorage;
 use crate::witness_gen;
 use crate::{
-    CrateReport, GlobalConfig, ReleaseType, WitnessGeneration,
+    AcceptedBreakage, AllowedBy, CrateReport, GlobalConfig, LintFinding, LintResult, OutputFormat,
+    ReleaseType, WitnessGeneration,
     query::{ActualSemverUpdate, LintLevel, OverrideStack, RequiredSemverUpdate, SemverQuery},
 };
 
@@ -116,13 +118,65 @@ fn get_minimum_version_change(version: &semver::Version) -> VersionChange {
     }
 }
 
+/// Helper function to render the results of a triggered lint.
+fn collect_lint_results(
+    config: &GlobalConfig,
+    semver_query: &SemverQuery,
+    results: Vec<BTreeMap<Arc<str>, FieldValue>>,
+    allowed_by: Option<AllowedBy>,
+) -> Vec<LintResult> {
+    results
+        .into_iter()
+        .map(|values| {
+            let message = semver_query
+                .per_result_error_template
+                .as_deref()
+                .map(|template| {
+                    let pretty_result: BTreeMap<&Arc<str>, TransparentValue> =
+                        values.iter().map(|(k, v)| (k, v.clone().into())).collect();
+                    config
+                        .handlebars()
+                        .render_template(template, &pretty_result)
+                        .context("Error instantiating semver query template.")
+                        .expect("could not materialize template")
+                });
+            LintResult {
+                values,
+                message,
+                allowed_by,
+            }
+        })
+        .collect()
+}
+
+/// Computes the results of the `rust_version_increased` lint.
+///
+/// `rust-version` isn't available through the rustdoc adapter, so this compares
+/// the values from the `cargo metadata` of both versions instead of running a query.
+fn rust_version_increased(
+    data_storage: &DataStorage,
+    crate_name: &str,
+) -> Vec<BTreeMap<Arc<str>, FieldValue>> {
+    match (
+        data_storage.baseline_rust_version(),
+        data_storage.current_rust_version(),
+    ) {
+        (Some(old), Some(new)) if new > old => vec![BTreeMap::from([
+            (Arc::from("name"), crate_name.into()),
+            (Arc::from("old_rust_version"), old.to_string().into()),
+            (Arc::from("new_rust_version"), new.to_string().into()),
+        ])],
+        _ => vec![],
+    }
+}
+
 /// Helper function to print details about a triggered lint.
 fn print_triggered_lint(
     config: &mut GlobalConfig,
-    semver_query: &SemverQuery,
-    results: Vec<BTreeMap<Arc<str>, FieldValue>>,
+    finding: &LintFinding,
     witness_generation: &WitnessGeneration,
 ) -> anyhow::Result<()> {
+    let semver_query = &finding.query;
     if let Some(ref_link) = semver_query.reference_link.as_deref() {
         config.log_info(|config| {
             writeln!(config.stdout(), "{}Description:{}\n{}\n{:>12} {}\n{:>12} https://github.com/obi1kenobi/cargo-semver-checks/tree/v{}/src/lints/{}.ron\n",
@@ -162,18 +216,14 @@ fn print_triggered_lint(
         Ok(())
     })?;
 
-    for semver_violation_result in results {
-        let pretty_result: BTreeMap<Arc<str>, TransparentValue> = semver_violation_result
-            .into_iter()
-            .map(|(k, v)| (k, v.into()))
+    for semver_violation_result in &finding.results {
+        let pretty_result: BTreeMap<&Arc<str>, TransparentValue> = semver_violation_result
+            .values
+            .iter()
+            .map(|(k, v)| (k, v.clone().into()))
             .collect();
 
-        if let Some(template) = semver_query.per_result_error_template.as_deref() {
-            let message = config
-                .handlebars()
-                .render_template(template, &pretty_result)
-                .context("Error instantiating semver query template.")
-                .expect("could not materialize template");
+        if let Some(message) = semver_violation_result.message.as_deref() {
             config.log_info(|config| {
                 writeln!(config.stdout(), "  {message}")?;
                 Ok(())
@@ -228,6 +278,7 @@ fn print_triggered_lint(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(super) fn run_check_release(
     config: &mut GlobalConfig,
     data_storage: &DataStorage,
@@ -235,6 +286,8 @@ pub(super) fn run_check_release(
     release_type: Option<ReleaseType>,
     overrides: &OverrideStack,
     witness_generation: &WitnessGeneration,
+    output_format: OutputFormat,
+    accepted_breakage: Option<&AcceptedBreakage>,
 ) -> anyhow::Result<CrateReport> {
     let current_version = data_storage.current_crate().crate_version();
     let baseline_version = data_storage.baseline_crate().crate_version();
@@ -292,6 +345,12 @@ pub(super) fn run_check_release(
                 && overrides.effective_lint_level(query) > LintLevel::Allow
         });
     let skipped_queries = queries_to_skip.len();
+    let lint_levels = queries_to_run
+        .iter()
+        .chain(&queries_to_skip)
+        .map(|query| (query.id.clone(), overrides.effective_lint_level(query)))
+        .collect();
+    let skipped_lints = queries_to_skip.into_iter().map(|query| query.id).collect();
 
     config.shell_status(
         "Checking",
@@ -332,11 +391,14 @@ pub(super) fn run_check_release(
         .par_iter()
         .map(|semver_query| {
             let start_instant = std::time::Instant::now();
-            // trustfall::execute_query(...) -> dyn Iterator (without Send)
-            // thus the result must be collect()'ed
-            let results = adapter
-                .run_query(&semver_query.query, semver_query.arguments.clone())?
-                .collect_vec();
+            let results = match semver_query.id.as_str() {
+                "rust_version_increased" => rust_version_increased(data_storage, crate_name),
+                // trustfall::execute_query(...) -> dyn Iterator (without Send)
+                // thus the result must be collect()'ed
+                _ => adapter
+                    .run_query(&semver_query.query, semver_query.arguments.clone())?
+                    .collect_vec(),
+            };
             let time_to_decide = start_instant.elapsed();
             Ok((semver_query, time_to_decide, results))
         })
@@ -346,9 +408,48 @@ pub(super) fn run_check_release(
         witness_gen::run_witness_checks(config, witness_dir, &adapter, &all_results);
     }
 
+    let allow_markers = AllowMarkers::collect(&adapter)?;
+
     let mut results_with_errors = vec![];
     let mut results_with_warnings = vec![];
+    let mut allowed_results = vec![];
+    let mut allowed_by_markers = vec![];
+    let mut passed_lints = vec![];
+    let mut lint_durations = BTreeMap::new();
+    let mut accepted_results = 0;
     for (semver_query, time_to_decide, results) in all_results {
+        lint_durations.insert(semver_query.id.clone(), time_to_decide);
+        let (accepted, results): (Vec<_>, Vec<_>) = results.into_iter().partition(|values| {
+            accepted_breakage.is_some_and(|accepted_breakage| {
+                accepted_breakage.accepts(&semver_query.id, values)
+            })
+        });
+        accepted_results += accepted.len();
+        let (allowed, results): (Vec<_>, Vec<_>) = results
+            .into_iter()
+            .partition(|values| allow_markers.allows(&semver_query.id, values));
+        if !allowed.is_empty() {
+            allowed_by_markers.push((semver_query.id.as_str(), allowed.len()));
+        }
+        // Accepted breakage is reported just like results allowed by markers.
+        let has_allowed_results = !allowed.is_empty() || !accepted.is_empty();
+        if has_allowed_results {
+            let mut results =
+                collect_lint_results(config, semver_query, allowed, Some(AllowedBy::Marker));
+            results.extend(collect_lint_results(
+                config,
+                semver_query,
+                accepted,
+                Some(AllowedBy::AcceptedBreakage),
+            ));
+            allowed_results.push(LintFinding {
+                query: semver_query.clone(),
+                lint_level: LintLevel::Allow,
+                required_update: overrides.effective_required_update(semver_query),
+                results,
+            });
+        }
+
         config
             .log_verbose(|config| {
                 let category = match overrides.effective_required_update(semver_query) {
@@ -384,10 +485,22 @@ pub(super) fn run_check_release(
             })
             .expect("print failed");
 
-        if !results.is_empty() {
-            match overrides.effective_lint_level(semver_query) {
-                LintLevel::Deny => results_with_errors.push((semver_query, results)),
-                LintLevel::Warn => results_with_warnings.push((semver_query, results)),
+        if results.is_empty() {
+            // Lints whose results were all allowed by markers or accepted as breakage
+            // didn't pass, and are only reported through their allowed findings.
+            if !has_allowed_results {
+                passed_lints.push(semver_query.id.clone());
+            }
+        } else {
+            let finding = LintFinding {
+                query: semver_query.clone(),
+                lint_level: overrides.effective_lint_level(semver_query),
+                required_update: overrides.effective_required_update(semver_query),
+                results: collect_lint_results(config, semver_query, results, None),
+            };
+            match finding.lint_level {
+                LintLevel::Deny => results_with_errors.push(finding),
+                LintLevel::Warn => results_with_warnings.push(finding),
                 LintLevel::Allow => unreachable!(
                     "`LintLevel::Allow` lint was unexpectedly not skipped: {semver_query:?}"
                 ),
@@ -395,8 +508,35 @@ pub(super) fn run_check_release(
         }
     }
 
+    for (lint_id, allowed) in allowed_by_markers {
+        config.shell_status(
+            "Allowed",
+            format_args!("{allowed} {lint_id} results by `{ALLOW_MARKER}...)` in item docs"),
+        )?;
+    }
+    if accepted_results > 0 {
+        config.shell_status(
+            "Ignoring",
+            format_args!("{accepted_results} results listed as accepted breakage"),
+        )?;
+    }
+
+    // Lints printed as human-readable text go to stdout,
+    // so skip them when stdout is reserved for a machine-readable report.
+    let print_findings = output_format == OutputFormat::Human;
+
     let produced_errors = !results_with_errors.is_empty();
     let produced_warnings = !results_with_warnings.is_empty();
+    // Lints whose results were all allowed neither pass nor fail.
+    let allowed_lints = queries_to_run.len()
+        - passed_lints.len()
+        - results_with_errors.len()
+        - results_with_warnings.len();
+    let allowed_summary = if allowed_lints > 0 {
+        format!(", {allowed_lints} allow")
+    } else {
+        String::new()
+    };
     if produced_errors || produced_warnings {
         let status_color = if produced_errors {
             AnsiColor::Red
@@ -407,10 +547,10 @@ pub(super) fn run_check_release(
             .shell_print(
                 "Checked",
                 format_args!(
-                    "[{:>8.3}s] {} checks: {} pass, {} fail, {} warn, {} skip",
+                    "[{:>8.3}s] {} checks: {} pass, {} fail, {} warn{allowed_summary}, {} skip",
                     queries_start_instant.elapsed().as_secs_f32(),
                     queries_to_run.len(),
-                    queries_to_run.len() - results_with_errors.len() - results_with_warnings.len(),
+                    passed_lints.len(),
                     results_with_errors.len(),
                     results_with_warnings.len(),
                     skipped_queries,
@@ -423,34 +563,38 @@ pub(super) fn run_check_release(
         let mut required_versions = vec![];
         let mut suggested_versions = vec![];
 
-        for (semver_query, results) in results_with_errors {
-            required_versions.push(overrides.effective_required_update(semver_query));
-            config.log_info(|config| {
-                writeln!(
-                    config.stdout(),
-                    "\n--- failure {}: {} ---\n",
-                    &semver_query.id,
-                    &semver_query.human_readable_name
-                )?;
-                Ok(())
-            })?;
+        for finding in &results_with_errors {
+            required_versions.push(finding.required_update);
+            if print_findings {
+                config.log_info(|config| {
+                    writeln!(
+                        config.stdout(),
+                        "\n--- failure {}: {} ---\n",
+                        &finding.query.id,
+                        &finding.query.human_readable_name
+                    )?;
+                    Ok(())
+                })?;
 
-            print_triggered_lint(config, semver_query, results, witness_generation)?;
+                print_triggered_lint(config, finding, witness_generation)?;
+            }
         }
 
-        for (semver_query, results) in results_with_warnings {
-            suggested_versions.push(overrides.effective_required_update(semver_query));
-            config.log_info(|config| {
-                writeln!(
-                    config.stdout(),
-                    "\n--- warning {}: {} ---\n",
-                    semver_query.id,
-                    semver_query.human_readable_name
-                )?;
-                Ok(())
-            })?;
+        for finding in &results_with_warnings {
+            suggested_versions.push(finding.required_update);
+            if print_findings {
+                config.log_info(|config| {
+                    writeln!(
+                        config.stdout(),
+                        "\n--- warning {}: {} ---\n",
+                        finding.query.id,
+                        finding.query.human_readable_name
+                    )?;
+                    Ok(())
+                })?;
 
-            print_triggered_lint(config, semver_query, results, witness_generation)?;
+                print_triggered_lint(config, finding, witness_generation)?;
+            }
         }
 
         let required_bump = required_versions.iter().max().copied();
@@ -515,19 +659,30 @@ pub(super) fn run_check_release(
             }
         }
 
+        let mut findings = results_with_errors;
+        findings.extend(results_with_warnings);
+        findings.extend(allowed_results);
+
         Ok(CrateReport {
             required_bump: required_bump.map(ReleaseType::from),
             detected_bump: version_change.level,
+            baseline_version: baseline_version.map(ToString::to_string),
+            current_version: current_version.map(ToString::to_string),
+            findings,
+            passed_lints,
+            skipped_lints,
+            lint_levels,
+            lint_durations,
         })
     } else {
         config
             .shell_print(
                 "Checked",
                 format_args!(
-                    "[{:>8.3}s] {} checks: {} pass, {} skip",
+                    "[{:>8.3}s] {} checks: {} pass{allowed_summary}, {} skip",
                     queries_start_instant.elapsed().as_secs_f32(),
                     queries_to_run.len(),
-                    queries_to_run.len(),
+                    passed_lints.len(),
                     skipped_queries,
                 ),
                 Color::Ansi(AnsiColor::Green),
@@ -545,6 +700,13 @@ pub(super) fn run_check_release(
         Ok(CrateReport {
             detected_bump: version_change.level,
             required_bump: None,
+            baseline_version: baseline_version.map(ToString::to_string),
+            current_version: current_version.map(ToString::to_string),
+            findings: allowed_results,
+            passed_lints,
+            skipped_lints,
+            lint_levels,
+            lint_durations,
         })
     }
 }
@@ -10,10 +10,21 @@ pub(crate) use generate::GenerationSettings;
 pub(crate) use progress::ProgressCallbacks;
 pub(crate) use request::{CacheSettings, CrateDataRequest};
 
+/// A crate's rustdoc data, together with the package metadata
+/// that isn't available through the rustdoc adapter.
+#[derive(Debug)]
+pub(crate) struct CrateData {
+    pub(crate) storage: VersionedStorage,
+    /// The package's `rust-version`, if it sets one and its `cargo metadata` was available.
+    pub(crate) rust_version: Option<semver::Version>,
+}
+
 #[derive(Debug)]
 pub(crate) struct DataStorage {
     current: VersionedStorage,
     baseline: VersionedStorage,
+    current_rust_version: Option<semver::Version>,
+    baseline_rust_version: Option<semver::Version>,
 
     // TODO: This is temporary, until we stop supporting formats older than rustdoc v45.
     // v45+ formats carry target triple information embedded inside the rustdoc JSON file.
@@ -22,13 +33,15 @@ pub(crate) struct DataStorage {
 
 impl DataStorage {
     pub(crate) fn new(
-        current: VersionedStorage,
-        baseline: VersionedStorage,
+        current: CrateData,
+        baseline: CrateData,
         target_triple: &'static str,
     ) -> Self {
         Self {
-            current,
-            baseline,
+            current: current.storage,
+            baseline: baseline.storage,
+            current_rust_version: current.rust_version,
+            baseline_rust_version: baseline.rust_version,
             target_triple,
         }
     }
@@ -40,6 +53,14 @@ impl DataStorage {
     pub(crate) fn baseline_crate(&self) -> &VersionedStorage {
         &self.baseline
     }
+
+    pub(crate) fn current_rust_version(&self) -> Option<&semver::Version> {
+        self.current_rust_version.as_ref()
+    }
+
+    pub(crate) fn baseline_rust_version(&self) -> Option<&semver::Version> {
+        self.baseline_rust_version.as_ref()
+    }
 }
 
 impl DataStorage {
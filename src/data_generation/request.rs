@@ -8,6 +8,7 @@ use trustfall_rustdoc::{LoadingError, VersionedStorage};
 use crate::manifest::Manifest;
 use crate::util::slugify;
 
+use super::CrateData;
 use super::error::{IntoTerminalResult, TerminalError};
 use super::generate::GenerationSettings;
 use super::progress::{CallbackHandler, ProgressCallbacks};
@@ -260,7 +261,7 @@ impl<'a> CrateDataRequest<'a> {
         cache_settings: CacheSettings<&'a Path>,
         generation_settings: GenerationSettings,
         callbacks: &'slf mut dyn ProgressCallbacks<'slf>,
-    ) -> Result<VersionedStorage, TerminalError> {
+    ) -> Result<CrateData, TerminalError> {
         let mut callbacks = CallbackHandler::new(
             self.kind
                 .name()
@@ -290,14 +291,18 @@ impl<'a> CrateDataRequest<'a> {
                 match std::fs::read_to_string(entry.metadata) {
                     Ok(text) => match serde_json::from_str(&text) {
                         Ok(metadata) => {
+                            let rust_version = package_rust_version(&metadata);
                             match load_rustdoc_with_optional_metadata(
                                 entry.json,
                                 metadata,
                                 &mut callbacks,
                             ) {
-                                Ok(data) => {
+                                Ok(storage) => {
                                     callbacks.parse_rustdoc_success(true);
-                                    return Ok(data);
+                                    return Ok(CrateData {
+                                        storage,
+                                        rust_version,
+                                    });
                                 }
                                 Err(e) => {
                                     callbacks.non_fatal_error(
@@ -362,7 +367,8 @@ impl<'a> CrateDataRequest<'a> {
 
         // This time, failure to read the rustdoc is fatal.
         callbacks.parse_rustdoc_start(false);
-        let data = load_rustdoc_with_optional_metadata(&data_path, metadata, &mut callbacks)
+        let rust_version = package_rust_version(&metadata);
+        let storage = load_rustdoc_with_optional_metadata(&data_path, metadata, &mut callbacks)
             .into_terminal_result()?;
         callbacks.parse_rustdoc_success(false);
 
@@ -376,7 +382,10 @@ impl<'a> CrateDataRequest<'a> {
             }
         }
 
-        Ok(data)
+        Ok(CrateData {
+            storage,
+            rust_version,
+        })
     }
 
     /// A path-safe unique identifier that includes the crate's source, name, version, and features.
@@ -422,6 +431,27 @@ fn load_rustdoc_with_optional_metadata(
     }
 }
 
+/// Finds the `rust-version` of the package whose rustdoc was generated.
+///
+/// The metadata is that of the placeholder project used to generate the rustdoc,
+/// whose only dependency is the package being checked. This mirrors how
+/// `trustfall_rustdoc` picks out that package's metadata.
+fn package_rust_version(metadata: &cargo_metadata::Metadata) -> Option<semver::Version> {
+    let dependency = metadata.root_package()?.dependencies.first()?;
+    metadata
+        .packages
+        .iter()
+        .find(|package| {
+            package.name.as_str() == dependency.name
+                && match &dependency.path {
+                    Some(path) => package.manifest_path.starts_with(path),
+                    None => dependency.req.matches(&package.version),
+                }
+        })?
+        .rust_version
+        .clone()
+}
+
 fn make_features_hash(default_features: bool, extra_features: &BTreeSet<Cow<'_, str>>) -> String {
     // Use newlines as the record separator, since newlines are not valid in feature names.
     let mut hasher = sha2::Sha256::new();
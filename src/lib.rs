@@ -1,10 +1,13 @@
 #![forbid(unsafe_code)]
 
+mod accepted_breakage;
+mod allow_markers;
 mod callbacks;
 mod check_release;
 mod config;
 mod data_generation;
 mod manifest;
+mod output;
 mod query;
 mod rustdoc_gen;
 mod templating;
@@ -22,14 +25,18 @@ use serde::Serialize;
 use std::collections::{BTreeMap, HashSet};
 use std::io::Write as _;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
 
 use check_release::run_check_release;
 use rustdoc_gen::CrateDataForRustdoc;
+use trustfall::FieldValue;
 
+pub use accepted_breakage::AcceptedBreakage;
 pub use config::{FeatureFlag, GlobalConfig};
 pub use query::{
-    ActualSemverUpdate, LintLevel, OverrideMap, OverrideStack, QueryOverride, RequiredSemverUpdate,
-    SemverQuery, Witness,
+    ActualSemverUpdate, LintGroup, LintLevel, OverrideMap, OverrideStack, QueryOverride,
+    RequiredSemverUpdate, SemverQuery, Witness,
 };
 
 /// Test a release for semver violations.
@@ -47,6 +54,16 @@ pub struct Check {
     build_target: Option<String>,
     /// Options for generating [witnesses](Witness).
     witness_generation: WitnessGeneration,
+    /// How to report the outcome of the check.
+    output_format: OutputFormat,
+    /// File to append a Markdown summary of the outcome to, if any.
+    markdown_summary: Option<PathBuf>,
+    /// Known lint results that should not be reported, if any.
+    accepted_breakage: Option<AcceptedBreakage>,
+    /// Lint configuration that takes precedence over any configuration in manifests.
+    lint_overrides: OverrideMap,
+    /// Config file to use instead of a `semver-checks.toml` at the workspace root, if any.
+    config_file: Option<PathBuf>,
 }
 
 /// The kind of release we're making.
@@ -61,6 +78,37 @@ pub enum ReleaseType {
     Patch,
 }
 
+impl ReleaseType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Major => "major",
+            Self::Minor => "minor",
+            Self::Patch => "patch",
+        }
+    }
+}
+
+/// The format in which the results of a check are reported.
+#[non_exhaustive]
+#[derive(ValueEnum, Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum OutputFormat {
+    /// Human-readable text, printed as each crate is checked.
+    #[default]
+    Human,
+    /// A single JSON document describing every checked crate, printed to stdout
+    /// once all crates have been checked.
+    Json,
+    /// A SARIF 2.1.0 log for code-scanning tools, printed to stdout
+    /// once all crates have been checked.
+    Sarif,
+    /// GitHub Actions workflow commands, annotating each finding as an error or warning,
+    /// printed to stdout once all crates have been checked.
+    GithubActions,
+    /// A JUnit XML report with a test suite per crate and a test case per lint,
+    /// printed to stdout once all crates have been checked.
+    Junit,
+}
+
 #[non_exhaustive]
 #[derive(Debug, PartialEq, Eq, Serialize)]
 pub struct Rustdoc {
@@ -276,6 +324,11 @@ impl Check {
             baseline_feature_config: rustdoc_gen::FeatureConfig::default_for_baseline(),
             build_target: None,
             witness_generation: WitnessGeneration::default(),
+            output_format: OutputFormat::default(),
+            markdown_summary: None,
+            accepted_breakage: None,
+            lint_overrides: OverrideMap::new(),
+            config_file: None,
         }
     }
 
@@ -346,6 +399,62 @@ impl Check {
         self
     }
 
+    /// Set the format in which the check results are reported. See [`OutputFormat`] for more.
+    pub fn set_output_format(&mut self, output_format: OutputFormat) -> &mut Self {
+        self.output_format = output_format;
+        self
+    }
+
+    /// Append a Markdown summary of the outcome to the given file, creating it if needed.
+    ///
+    /// Pass the path in `$GITHUB_STEP_SUMMARY` to show the summary on a GitHub Actions run page.
+    pub fn set_markdown_summary(&mut self, path: PathBuf) -> &mut Self {
+        self.markdown_summary = Some(path);
+        self
+    }
+
+    /// Don't report the lint results listed in the given [`AcceptedBreakage`].
+    pub fn set_accepted_breakage(&mut self, accepted_breakage: AcceptedBreakage) -> &mut Self {
+        self.accepted_breakage = Some(accepted_breakage);
+        self
+    }
+
+    /// Override the lint level or required version bump of the given lints and lint groups
+    /// for every checked crate.
+    ///
+    /// These overrides take precedence over any configuration in the checked crates' manifests.
+    /// Unlike manifest configuration, they also apply when checking rustdoc JSON files directly.
+    pub fn set_lint_overrides(&mut self, overrides: OverrideMap) -> &mut Self {
+        self.lint_overrides = overrides;
+        self
+    }
+
+    /// Read lint, feature, and target configuration from the given config file,
+    /// instead of from a `semver-checks.toml` file at the workspace root.
+    ///
+    /// The config file applies to every checked crate, including when checking
+    /// rustdoc JSON files directly. Configuration in the crates' manifests
+    /// and [lint overrides](Self::set_lint_overrides) take precedence over it.
+    pub fn set_config_file(&mut self, path: PathBuf) -> &mut Self {
+        self.config_file = Some(path);
+        self
+    }
+
+    /// Loads the config file that was set explicitly, or else the default config file
+    /// in the workspace root, if there is one.
+    fn load_config_file(
+        &self,
+        workspace_root: Option<&Path>,
+    ) -> anyhow::Result<Option<manifest::ConfigFile>> {
+        if let Some(path) = &self.config_file {
+            return manifest::ConfigFile::from_path(path).map(Some);
+        }
+        match workspace_root.map(|root| root.join(manifest::ConfigFile::DEFAULT_NAME)) {
+            Some(path) if path.is_file() => manifest::ConfigFile::from_path(&path).map(Some),
+            _ => Ok(None),
+        }
+    }
+
     /// Some `RustdocSource`s don't contain a path to the project root,
     /// so they don't have a target directory. We try to deduce the target directory
     /// on a "best effort" basis -- when the source contains a target dir,
@@ -430,7 +539,38 @@ impl Check {
             };
         }
 
-        let crates_to_check: Vec<CrateToCheck<'_>> = match &self.current.source {
+        let current_metadata = match &self.current.source {
+            RustdocSource::Root(project_root) => Some(manifest_metadata(project_root)?),
+            _ => None,
+        };
+
+        // Configuration from the config file applies to all crates,
+        // but anything set explicitly takes precedence over it.
+        let mut current_feature_config = self.current_feature_config.clone();
+        let mut baseline_feature_config = self.baseline_feature_config.clone();
+        let mut build_target = self.build_target.clone();
+        let mut config_overrides = OverrideStack::new();
+        let workspace_root = current_metadata
+            .as_ref()
+            .map(|metadata| metadata.workspace_root.as_std_path());
+        if let Some(config_file) = self.load_config_file(workspace_root)? {
+            current_feature_config
+                .extra_features
+                .extend(config_file.features.iter().cloned());
+            baseline_feature_config
+                .extra_features
+                .extend(config_file.features);
+            build_target = build_target.or(config_file.target);
+            for level in config_file
+                .lints
+                .map(|lints| lints.into_stack())
+                .unwrap_or_default()
+            {
+                config_overrides.push(&level);
+            }
+        }
+
+        let mut crates_to_check: Vec<CrateToCheck<'_>> = match &self.current.source {
             RustdocSource::Rustdoc(_)
             | RustdocSource::Revision(_, _)
             | RustdocSource::VersionFromRegistry(_) => {
@@ -453,28 +593,30 @@ impl Check {
                     .map(|name| {
                         let version = None;
                         CrateToCheck {
-                            overrides: OverrideStack::new(),
+                            overrides: config_overrides.clone(),
                             current_crate_data: CrateDataForRustdoc {
                                 crate_type: rustdoc_gen::CrateType::Current,
                                 name: name.clone(),
-                                feature_config: &self.current_feature_config,
-                                build_target: self.build_target.as_deref(),
+                                feature_config: &current_feature_config,
+                                build_target: build_target.as_deref(),
                             },
                             baseline_crate_data: CrateDataForRustdoc {
                                 crate_type: rustdoc_gen::CrateType::Baseline {
                                     highest_allowed_version: version,
                                 },
                                 name,
-                                feature_config: &self.baseline_feature_config,
-                                build_target: self.build_target.as_deref(),
+                                feature_config: &baseline_feature_config,
+                                build_target: build_target.as_deref(),
                             },
                         }
                     })
                     .collect()
             }
-            RustdocSource::Root(project_root) => {
-                let metadata = manifest_metadata(project_root)?;
-                let (selected, skipped) = self.scope.selected_packages(&metadata);
+            RustdocSource::Root(_) => {
+                let metadata = current_metadata
+                    .as_ref()
+                    .expect("metadata is loaded for project roots");
+                let (selected, skipped) = self.scope.selected_packages(metadata);
                 if selected.is_empty() {
                     let help = if skipped.is_empty() {
                         "".to_string()
@@ -520,6 +662,7 @@ note: skipped the following crates since they have no library target: {skipped}"
                         } else {
                             let overrides = overrides_for_workspace_package(
                                 selected,
+                                &config_overrides,
                                 workspace_overrides.as_deref(),
                             )?;
 
@@ -528,16 +671,16 @@ note: skipped the following crates since they have no library target: {skipped}"
                                 current_crate_data: CrateDataForRustdoc {
                                     crate_type: rustdoc_gen::CrateType::Current,
                                     name: crate_name.to_string(),
-                                    feature_config: &self.current_feature_config,
-                                    build_target: self.build_target.as_deref(),
+                                    feature_config: &current_feature_config,
+                                    build_target: build_target.as_deref(),
                                 },
                                 baseline_crate_data: CrateDataForRustdoc {
                                     crate_type: rustdoc_gen::CrateType::Baseline {
                                         highest_allowed_version: Some(version.clone()),
                                     },
                                     name: crate_name.to_string(),
-                                    feature_config: &self.baseline_feature_config,
-                                    build_target: self.build_target.as_deref(),
+                                    feature_config: &baseline_feature_config,
+                                    build_target: build_target.as_deref(),
                                 },
                             }))
                         }
@@ -547,6 +690,12 @@ note: skipped the following crates since they have no library target: {skipped}"
             }
         };
 
+        if !self.lint_overrides.is_empty() {
+            for selected in &mut crates_to_check {
+                selected.overrides.push(&self.lint_overrides);
+            }
+        }
+
         let current_loader = self.get_rustdoc_generator(config, &self.current.source)?;
         let baseline_loader = self.get_rustdoc_generator(config, &self.baseline.source)?;
 
@@ -593,6 +742,8 @@ note: skipped the following crates since they have no library target: {skipped}"
                     self.release_type,
                     &selected.overrides,
                     &self.witness_generation,
+                    self.output_format,
+                    self.accepted_breakage.as_ref(),
                 )?;
                 config.shell_status(
                     "Finished",
@@ -610,12 +761,32 @@ note: skipped the following crates since they have no library target: {skipped}"
             reports
         };
 
-        Ok(Report { crate_reports })
+        let report = Report { crate_reports };
+        // Baselines checked out from git or downloaded from a registry
+        // are placed in the current project's target directory.
+        let source_roots = match self.output_format {
+            OutputFormat::Sarif | OutputFormat::GithubActions => {
+                current_metadata.as_ref().map(|metadata| {
+                    output::SourceRoots::new(
+                        metadata.workspace_root.clone().into_std_path_buf(),
+                        vec![metadata.target_directory.as_std_path().join(util::SCOPE)],
+                    )
+                })
+            }
+            OutputFormat::Human | OutputFormat::Json | OutputFormat::Junit => None,
+        };
+        output::write_report(config, self.output_format, &report, source_roots.as_ref())?;
+        if let Some(path) = &self.markdown_summary {
+            output::write_markdown_summary(config, &report, &self.witness_generation, path)?;
+        }
+
+        Ok(report)
     }
 }
 
 fn overrides_for_workspace_package(
     package: &cargo_metadata::Package,
+    config_overrides: &OverrideStack,
     workspace_overrides: Option<&[BTreeMap<String, QueryOverride>]>,
 ) -> Result<OverrideStack, anyhow::Error> {
     let lint_table = manifest::deserialize_lint_table(&package.metadata).with_context(|| {
@@ -634,7 +805,7 @@ fn overrides_for_workspace_package(
     );
     let metadata_workspace_key = lint_table.as_ref().is_some_and(|x| x.workspace);
 
-    let mut overrides = OverrideStack::new();
+    let mut overrides = config_overrides.clone();
     if use_workspace_lints || metadata_workspace_key {
         if let Some(workspace) = workspace_overrides {
             for level in workspace {
@@ -676,6 +847,25 @@ pub struct CrateReport {
     /// For example, if the crate contains breaking changes, this is [`Some(ReleaseType::Major)`].
     /// If no additional bump beyond the already-detected one is required, this is [`Option::None`].
     required_bump: Option<ReleaseType>,
+    /// Version of the baseline crate, if known.
+    baseline_version: Option<String>,
+    /// Version of the current crate, if known.
+    current_version: Option<String>,
+    /// Lints that reported at least one result: errors first, then warnings,
+    /// then results allowed by markers in the docs of the items they point to
+    /// or listed as accepted breakage.
+    findings: Vec<LintFinding>,
+    /// Ids of the lints that were executed and reported no results,
+    /// not even allowed ones.
+    passed_lints: Vec<String>,
+    /// Ids of the lints that were not executed, either because they are allowed
+    /// or because the detected version bump already permits their findings.
+    skipped_lints: Vec<String>,
+    /// Level of every lint after applying the configured overrides, by lint id,
+    /// whether or not the lint was executed.
+    lint_levels: BTreeMap<String, LintLevel>,
+    /// Time taken to run each executed lint, by lint id.
+    lint_durations: BTreeMap<String, Duration>,
 }
 
 impl CrateReport {
@@ -720,6 +910,106 @@ impl CrateReport {
     pub fn detected_bump(&self) -> ActualSemverUpdate {
         self.detected_bump
     }
+
+    /// Lints that reported at least one result: errors first, then warnings,
+    /// and then results allowed by a `semver-checks: allow(<lint>)` marker in the docs
+    /// of the items they point to or listed in the [`AcceptedBreakage`],
+    /// which have [`LintLevel::Allow`] level.
+    ///
+    /// A lint may have two findings if only some of its results were allowed this way.
+    /// Lints whose results were all allowed only have the [`LintLevel::Allow`] finding,
+    /// and are not counted as passed.
+    ///
+    /// Lints that were allowed, or whose results are already permitted
+    /// by the detected bump, are not run and therefore never appear here.
+    pub fn findings(&self) -> &[LintFinding] {
+        &self.findings
+    }
+}
+
+/// A lint that reported results when checking a crate.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct LintFinding {
+    /// The lint that reported the results.
+    pub(crate) query: SemverQuery,
+    /// The lint level in effect after applying the configured overrides.
+    pub(crate) lint_level: LintLevel,
+    /// The required version bump in effect after applying the configured overrides.
+    pub(crate) required_update: RequiredSemverUpdate,
+    /// The results reported by the lint, in the order the query produced them.
+    pub(crate) results: Vec<LintResult>,
+}
+
+impl LintFinding {
+    /// The lint that reported the results.
+    pub fn query(&self) -> &SemverQuery {
+        &self.query
+    }
+
+    /// The lint level in effect for this crate, after applying the configured overrides.
+    ///
+    /// This may differ from the lint's default level in [`SemverQuery::lint_level`].
+    pub fn lint_level(&self) -> LintLevel {
+        self.lint_level
+    }
+
+    /// The version bump required by this lint's results, after applying the configured overrides.
+    ///
+    /// This may differ from the lint's default in [`SemverQuery::required_update`].
+    pub fn required_update(&self) -> RequiredSemverUpdate {
+        self.required_update
+    }
+
+    /// The results reported by the lint. Never empty.
+    pub fn results(&self) -> &[LintResult] {
+        &self.results
+    }
+}
+
+/// A single result reported by a lint.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct LintResult {
+    /// The `@output` values produced by the lint's query.
+    pub(crate) values: BTreeMap<Arc<str>, FieldValue>,
+    /// The lint's `per_result_error_template` rendered with this result's values,
+    /// if the lint defines such a template.
+    pub(crate) message: Option<String>,
+    /// Why the result was allowed, if it was allowed despite its lint being enabled.
+    pub(crate) allowed_by: Option<AllowedBy>,
+}
+
+/// What allowed a lint result that would otherwise have been reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AllowedBy {
+    /// A `semver-checks: allow(<lint>)` marker in the docs of the flagged item.
+    Marker,
+    /// An entry in the [`AcceptedBreakage`] file.
+    AcceptedBreakage,
+}
+
+impl LintResult {
+    /// The `@output` values produced by the lint's query for this result.
+    pub fn values(&self) -> &BTreeMap<Arc<str>, FieldValue> {
+        &self.values
+    }
+
+    /// The lint's per-result error message, rendered with this result's values.
+    /// It's [`Option::None`] if the lint doesn't define a per-result message template.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// The location of the item that triggered the lint, as a `(filename, begin_line)` pair.
+    ///
+    /// Lints report this via their `span_filename` and `span_begin_line` outputs.
+    /// Returns `None` if the lint didn't report a span, or if it reported several.
+    pub fn span(&self) -> Option<(&str, u64)> {
+        let filename = self.values.get("span_filename")?.as_str()?;
+        let begin_line = self.values.get("span_begin_line")?.as_u64()?;
+        Some((filename, begin_line))
+    }
 }
 
 /// Report of the whole analysis.
@@ -783,7 +1073,7 @@ fn generate_crate_data(
     )?;
 
     let baseline_crate_name = &baseline_loader.get_crate_data().name;
-    let current_rustdoc_version = current_crate.version();
+    let current_rustdoc_version = current_crate.storage.version();
 
     let baseline_crate = {
         let mut baseline_crate = baseline_loader.load_rustdoc(
@@ -799,7 +1089,7 @@ fn generate_crate_data(
         // If the baseline rustdoc version doesn't match, delete the cached baseline and rebuild it.
         //
         // Fix for: https://github.com/obi1kenobi/cargo-semver-checks/issues/415
-        if baseline_crate.version() != current_rustdoc_version {
+        if baseline_crate.storage.version() != current_rustdoc_version {
             let crate_name = baseline_crate_name;
             config
                 .shell_status(
@@ -815,7 +1105,7 @@ fn generate_crate_data(
             )?;
 
             assert_eq!(
-                baseline_crate.version(),
+                baseline_crate.storage.version(),
                 current_rustdoc_version,
                 "Deleting and regenerating the baseline JSON file did not resolve the rustdoc \
                  version mismatch."
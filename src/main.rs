@@ -5,7 +5,8 @@ use std::{collections::HashSet, env, path::PathBuf};
 use anstyle::{AnsiColor, Color, Reset, Style};
 use cargo_config2::Config;
 use cargo_semver_checks::{
-    FeatureFlag, GlobalConfig, PackageSelection, ReleaseType, Rustdoc, ScopeSelection, SemverQuery,
+    AcceptedBreakage, FeatureFlag, GlobalConfig, LintGroup, LintLevel, OutputFormat, OverrideMap,
+    PackageSelection, ReleaseType, RequiredSemverUpdate, Rustdoc, ScopeSelection, SemverQuery,
     WitnessGeneration,
 };
 use clap::{Args, CommandFactory, Parser, Subcommand};
@@ -38,27 +39,39 @@ fn main() {
     else if args.list {
         exit_on_error(true, || {
             let queries = SemverQuery::all_queries();
-            let mut rows = vec![["id", "type", "description"], ["==", "====", "==========="]];
-            for query in queries.values() {
+            let groups: Vec<String> = queries
+                .values()
+                .map(|query| {
+                    let names: Vec<_> = query.groups.iter().map(|group| group.as_str()).collect();
+                    names.join(",")
+                })
+                .collect();
+            let mut rows = vec![
+                ["id", "type", "groups", "description"],
+                ["==", "====", "======", "==========="],
+            ];
+            for (query, groups) in queries.values().zip(&groups) {
                 rows.push([
                     query.id.as_str(),
                     query.required_update.as_str(),
+                    groups.as_str(),
                     query.description.as_str(),
                 ]);
             }
-            let mut widths = [0; 3];
+            let mut widths = [0; 4];
             for row in &rows {
                 widths[0] = widths[0].max(row[0].len());
                 widths[1] = widths[1].max(row[1].len());
                 widths[2] = widths[2].max(row[2].len());
+                widths[3] = widths[3].max(row[3].len());
             }
             let stdout = std::io::stdout();
             let mut stdout = stdout.lock();
             for row in rows {
                 writeln!(
                     stdout,
-                    "{0:<1$} {2:<3$} {4:<5$}",
-                    row[0], widths[0], row[1], widths[1], row[2], widths[2]
+                    "{0:<1$} {2:<3$} {4:<5$} {6:<7$}",
+                    row[0], widths[0], row[1], widths[1], row[2], widths[2], row[3], widths[3]
                 )?;
             }
 
@@ -85,6 +98,11 @@ fn main() {
                     .as_deref()
                     .unwrap_or(query.description.as_str())
             );
+            if !query.groups.is_empty() {
+                let groups: Vec<_> = query.groups.iter().map(|group| group.as_str()).collect();
+                println!();
+                println!("Groups: {}", groups.join(", "));
+            }
             if let Some(link) = &query.reference_link {
                 println!();
                 println!("See also {link}");
@@ -157,9 +175,52 @@ fn main() {
         None => args.check_release,
     };
 
-    let check: cargo_semver_checks::Check = check_release.into();
+    if matches!(check_release.markdown_summary, Some(None))
+        && env::var_os("GITHUB_STEP_SUMMARY").is_none()
+    {
+        exit_on_error::<()>(true, || {
+            anyhow::bail!(
+                "`--markdown-summary` was given without a path, \
+                 and the GITHUB_STEP_SUMMARY environment variable is not set"
+            )
+        });
+    }
+
+    let accepted_breakage_path = check_release.accepted_breakage.clone();
+    let write_accepted_breakage = check_release.write_accepted_breakage;
+    let lint_overrides = exit_on_error(true, || check_release.lint_overrides());
+
+    let mut check: cargo_semver_checks::Check = check_release.into();
+    check.set_lint_overrides(lint_overrides);
+    // When rewriting the accepted breakage, every breaking change must be reported.
+    if let (Some(path), false) = (&accepted_breakage_path, write_accepted_breakage) {
+        let accepted_breakage = exit_on_error(true, || AcceptedBreakage::from_path(path));
+        check.set_accepted_breakage(accepted_breakage);
+    }
 
     let report = exit_on_error(config.is_error(), || check.check_release(&mut config));
+
+    if write_accepted_breakage {
+        let path = accepted_breakage_path.expect("clap requires --accepted-breakage");
+        exit_on_error(true, || {
+            let mut accepted_breakage = if path.exists() {
+                AcceptedBreakage::from_path(&path)?
+            } else {
+                AcceptedBreakage::default()
+            };
+            accepted_breakage.update_from_report(&report);
+            accepted_breakage.write_to(&path)?;
+            config.shell_status(
+                "Wrote",
+                format_args!(
+                    "{} accepted results to {}",
+                    accepted_breakage.len(),
+                    path.display()
+                ),
+            )
+        });
+        std::process::exit(0);
+    }
     if report.success() {
         std::process::exit(0);
     } else {
@@ -545,10 +606,142 @@ struct CheckRelease {
     #[arg(long = "target")]
     build_target: Option<String>,
 
+    /// Read lint, feature, and target configuration from the given TOML file.
+    ///
+    /// Defaults to `semver-checks.toml` in the workspace root, if that file exists.
+    /// Its `[lints]` table is configured the same way as in
+    /// `[package.metadata.cargo-semver-checks.lints]`.
+    #[arg(long, value_name = "PATH")]
+    config: Option<PathBuf>,
+
+    /// How to report the results of the check.
+    ///
+    /// With any format other than `human`, the results for all checked crates
+    /// are printed to stdout once checking is complete.
+    #[arg(long, value_enum, value_name = "FORMAT", default_value_t = OutputFormat::Human)]
+    output_format: OutputFormat,
+
+    /// Append a Markdown summary of the results to the given file.
+    ///
+    /// If no file is given, the summary is appended to the file named by
+    /// the `GITHUB_STEP_SUMMARY` environment variable, which GitHub Actions
+    /// shows on the workflow run's summary page.
+    #[arg(long, value_name = "PATH", num_args = 0..=1, require_equals = true)]
+    markdown_summary: Option<Option<PathBuf>>,
+
+    /// Don't report breaking changes listed in the given TOML file,
+    /// such as `semver-checks-baseline.toml`.
+    ///
+    /// Each entry in the file names a lint and the path of an item it flagged,
+    /// for example `lint = "function_missing"` and `path = "my_crate::removed_fn"`.
+    #[arg(long, value_name = "PATH")]
+    accepted_breakage: Option<PathBuf>,
+
+    /// Record every reported breaking change into the `--accepted-breakage` file,
+    /// replacing its previous contents, instead of failing the check.
+    #[arg(long, requires = "accepted_breakage")]
+    write_accepted_breakage: bool,
+
+    /// Treat results of the given lints or lint groups as errors,
+    /// overriding any configuration in `Cargo.toml`.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "LINT",
+        help_heading = "Lints"
+    )]
+    deny: Vec<String>,
+
+    /// Treat results of the given lints or lint groups as warnings,
+    /// overriding any configuration in `Cargo.toml`.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "LINT",
+        help_heading = "Lints"
+    )]
+    warn: Vec<String>,
+
+    /// Don't run the given lints or lint groups,
+    /// overriding any configuration in `Cargo.toml`.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "LINT",
+        help_heading = "Lints"
+    )]
+    allow: Vec<String>,
+
+    /// Set the version bump required by a lint or lint group, e.g. `function_missing=minor`,
+    /// overriding any configuration in `Cargo.toml`.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "LINT=major|minor",
+        value_parser = parse_required_update,
+        help_heading = "Lints"
+    )]
+    required_update: Vec<(String, RequiredSemverUpdate)>,
+
     #[clap(flatten)]
     unstable_options: UnstableOptions,
 }
 
+fn parse_required_update(value: &str) -> Result<(String, RequiredSemverUpdate), String> {
+    let (lint, update) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected `LINT=major` or `LINT=minor`, got `{value}`"))?;
+    let update = match update {
+        "major" => RequiredSemverUpdate::Major,
+        "minor" => RequiredSemverUpdate::Minor,
+        _ => return Err(format!("expected `major` or `minor`, got `{update}`")),
+    };
+    Ok((lint.to_string(), update))
+}
+
+impl CheckRelease {
+    /// Collects the lint configuration given by `--deny`, `--warn`, `--allow`,
+    /// and `--required-update` flags.
+    fn lint_overrides(&self) -> anyhow::Result<OverrideMap> {
+        let queries = SemverQuery::all_queries();
+        let check_known = |id: &str| {
+            if queries.contains_key(id) || LintGroup::ALL.iter().any(|group| group.as_str() == id) {
+                Ok(())
+            } else {
+                Err(anyhow::format_err!(
+                    "unknown lint or lint group `{id}`, use `--list` to see available lints"
+                ))
+            }
+        };
+
+        let mut overrides = OverrideMap::new();
+        for (ids, level) in [
+            (&self.deny, LintLevel::Deny),
+            (&self.warn, LintLevel::Warn),
+            (&self.allow, LintLevel::Allow),
+        ] {
+            for id in ids {
+                check_known(id)?;
+                let entry = overrides.entry(id.clone()).or_default();
+                if let Some(previous) = entry.lint_level.replace(level) {
+                    if previous != level {
+                        anyhow::bail!(
+                            "lint `{id}` was given both `--{}` and `--{}`",
+                            previous.as_str(),
+                            level.as_str()
+                        );
+                    }
+                }
+            }
+        }
+        for (id, required_update) in &self.required_update {
+            check_known(id)?;
+            overrides.entry(id.clone()).or_default().required_update = Some(*required_update);
+        }
+        Ok(overrides)
+    }
+}
+
 impl From<CheckRelease> for cargo_semver_checks::Check {
     fn from(value: CheckRelease) -> Self {
         let (current, current_project_root) = if let Some(current_rustdoc) = value.current_rustdoc {
@@ -640,10 +833,25 @@ impl From<CheckRelease> for cargo_semver_checks::Check {
             check.set_build_target(build_target);
         }
 
+        if let Some(config) = value.config {
+            check.set_config_file(config);
+        }
+
         let mut witness_generation = WitnessGeneration::new();
         witness_generation.show_hints = value.unstable_options.witness_hints;
         check.set_witness_generation(witness_generation);
 
+        check.set_output_format(value.output_format);
+
+        let markdown_summary = match value.markdown_summary {
+            Some(Some(path)) => Some(path),
+            Some(None) => env::var_os("GITHUB_STEP_SUMMARY").map(PathBuf::from),
+            None => None,
+        };
+        if let Some(path) = markdown_summary {
+            check.set_markdown_summary(path);
+        }
+
         check
     }
 }
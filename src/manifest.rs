@@ -98,6 +98,34 @@ pub(crate) struct SemverChecksTable {
     pub(crate) lints: Option<LintTable>,
 }
 
+/// A standalone `semver-checks.toml` config file, which configures checks
+/// without needing a `Cargo.toml` manifest.
+///
+/// Its `lints` table has the same schema as in `[package.metadata.cargo-semver-checks]`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ConfigFile {
+    /// Holds the `lints` table, if it is declared.
+    pub(crate) lints: Option<LintTable>,
+    /// Features to enable in both the baseline and the current version of each crate.
+    #[serde(default)]
+    pub(crate) features: Vec<String>,
+    /// Which target to build the crates for, unless set on the command line.
+    pub(crate) target: Option<String>,
+}
+
+impl ConfigFile {
+    /// The name of the config file that is used automatically when found at the workspace root.
+    pub(crate) const DEFAULT_NAME: &str = "semver-checks.toml";
+
+    pub(crate) fn from_path(path: &std::path::Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read config file {}", path.display()))?;
+        toml::from_str(&contents)
+            .with_context(|| format!("config file {} is invalid", path.display()))
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub(crate) struct LintTable {
     /// Optional key to indicate whether to opt-in to reading
@@ -252,7 +280,7 @@ pub(crate) fn deserialize_lint_table(
 #[cfg(test)]
 mod tests {
 
-    use super::{LintTable, MetadataTable};
+    use super::{ConfigFile, LintTable, MetadataTable};
     use crate::{OverrideMap, QueryOverride};
 
     #[test]
@@ -383,6 +411,70 @@ mod tests {
         assert!(!table.workspace, "table.workspace should be false");
     }
 
+    #[test]
+    fn group_entries_resolve_with_priority() {
+        use crate::{LintLevel, OverrideStack, SemverQuery};
+
+        let table = toml::from_str::<LintTable>(
+            r#"
+            deprecation = "allow"
+            function_marked_deprecated = "deny"
+            doc_hidden = { level = "warn", priority = -1 }
+            function_now_doc_hidden = "deny"
+            "#,
+        )
+        .expect("this should be a valid lint table");
+
+        let mut stack = OverrideStack::new();
+        for map in table.into_stack() {
+            stack.push(&map);
+        }
+
+        let queries = SemverQuery::all_queries();
+        let level = |id: &str| stack.effective_lint_level(&queries[id]);
+
+        // The group applies to all its lints, except ones configured individually
+        // with the same priority.
+        assert_eq!(level("enum_variant_marked_deprecated"), LintLevel::Allow);
+        assert_eq!(level("function_marked_deprecated"), LintLevel::Deny);
+        // A group entry with a more negative priority overrides individual entries.
+        assert_eq!(level("function_now_doc_hidden"), LintLevel::Warn);
+        assert_eq!(level("trait_now_doc_hidden"), LintLevel::Warn);
+        // Lints outside of the configured groups are unaffected.
+        assert_eq!(level("function_missing"), LintLevel::Deny);
+    }
+
+    #[test]
+    fn test_deserialize_config_file() {
+        use crate::LintLevel::*;
+
+        let config: ConfigFile = toml::from_str(
+            r#"
+            features = ["serde"]
+            target = "x86_64-unknown-linux-gnu"
+
+            [lints]
+            function_missing = "warn"
+            "#,
+        )
+        .expect("config file should be valid");
+        assert_eq!(config.features, ["serde"]);
+        assert_eq!(config.target.as_deref(), Some("x86_64-unknown-linux-gnu"));
+        similar_asserts::assert_eq!(
+            config.lints.expect("lints should be present").into_stack(),
+            vec![OverrideMap::from_iter([(
+                "function_missing".into(),
+                QueryOverride {
+                    lint_level: Some(Warn),
+                    required_update: None,
+                }
+            )])]
+        );
+
+        toml::from_str::<ConfigFile>("[package]\nname = \"oops\"")
+            .expect_err("unknown keys should be invalid");
+    }
+
     #[test]
     fn entry_with_no_fields_is_error() {
         toml::from_str::<LintTable>("one = {}").expect_err("one = {} should be invalid");
@@ -0,0 +1,60 @@
+use std::io::Write as _;
+
+use crate::{GlobalConfig, LintLevel, Report};
+
+use super::SourceRoots;
+
+/// Escapes the message of a GitHub Actions workflow command.
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escapes a property value of a GitHub Actions workflow command,
+/// such as `file` or `title`.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Prints a GitHub Actions `::error` or `::warning` annotation for each lint result.
+///
+/// Results with a span in the repository are attached to the corresponding source line,
+/// while all other results are emitted as annotations without a file.
+pub(super) fn write_report(
+    config: &mut GlobalConfig,
+    report: &Report,
+    source_roots: Option<&SourceRoots>,
+) -> anyhow::Result<()> {
+    let mut stdout = config.stdout();
+    for crate_report in report.crate_reports().values() {
+        for finding in &crate_report.findings {
+            let command = match finding.lint_level {
+                LintLevel::Deny => "error",
+                LintLevel::Warn => "warning",
+                LintLevel::Allow => continue,
+            };
+            let query = &finding.query;
+            let title = escape_property(&format!("{}: {}", query.id, query.human_readable_name));
+
+            for result in &finding.results {
+                let location = result
+                    .span()
+                    .and_then(|(filename, begin_line)| {
+                        let filename = source_roots?.span_path(filename)?;
+                        Some(format!(
+                            "file={},line={begin_line},",
+                            escape_property(&filename)
+                        ))
+                    })
+                    .unwrap_or_default();
+                let message =
+                    escape_data(result.message.as_deref().unwrap_or(&query.error_message));
+
+                writeln!(stdout, "::{command} {location}title={title}::{message}")?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use std::collections::BTreeMap;
+use std::io::Write as _;
+use std::sync::Arc;
+
+use clap::crate_version;
+use serde::Serialize;
+use trustfall::TransparentValue;
+
+use crate::{CrateReport, GlobalConfig, LintFinding, LintResult, Report};
+
+/// The JSON document describing a whole `cargo-semver-checks` run.
+#[derive(Debug, Serialize)]
+struct JsonReport<'a> {
+    cargo_semver_checks_version: &'static str,
+    success: bool,
+    crates: BTreeMap<&'a str, JsonCrateReport<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonCrateReport<'a> {
+    success: bool,
+    baseline_version: Option<&'a str>,
+    current_version: Option<&'a str>,
+    detected_bump: &'static str,
+    required_bump: Option<&'static str>,
+    findings: Vec<JsonFinding<'a>>,
+    passed_lints: &'a [String],
+    skipped_lints: &'a [String],
+}
+
+#[derive(Debug, Serialize)]
+struct JsonFinding<'a> {
+    id: &'a str,
+    human_readable_name: &'a str,
+    description: &'a str,
+    error_message: &'a str,
+    reference_link: Option<&'a str>,
+    lint_level: &'static str,
+    required_update: &'static str,
+    results: Vec<JsonResult<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonResult<'a> {
+    message: Option<&'a str>,
+    span: Option<JsonSpan<'a>>,
+    values: BTreeMap<&'a str, TransparentValue>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonSpan<'a> {
+    filename: &'a str,
+    begin_line: u64,
+}
+
+impl<'a> JsonCrateReport<'a> {
+    fn new(report: &'a CrateReport) -> Self {
+        Self {
+            success: report.success(),
+            baseline_version: report.baseline_version.as_deref(),
+            current_version: report.current_version.as_deref(),
+            detected_bump: report.detected_bump.as_str(),
+            required_bump: report.required_bump.map(|bump| bump.as_str()),
+            findings: report.findings.iter().map(JsonFinding::new).collect(),
+            passed_lints: &report.passed_lints,
+            skipped_lints: &report.skipped_lints,
+        }
+    }
+}
+
+impl<'a> JsonFinding<'a> {
+    fn new(finding: &'a LintFinding) -> Self {
+        let query = &finding.query;
+        Self {
+            id: &query.id,
+            human_readable_name: &query.human_readable_name,
+            description: &query.description,
+            error_message: &query.error_message,
+            reference_link: query.reference_link.as_deref(),
+            lint_level: finding.lint_level.as_str(),
+            required_update: finding.required_update.as_str(),
+            results: finding.results.iter().map(JsonResult::new).collect(),
+        }
+    }
+}
+
+impl<'a> JsonResult<'a> {
+    fn new(result: &'a LintResult) -> Self {
+        Self {
+            message: result.message.as_deref(),
+            span: result.span().map(|(filename, begin_line)| JsonSpan {
+                filename,
+                begin_line,
+            }),
+            values: result
+                .values
+                .iter()
+                .map(|(k, v)| (Arc::as_ref(k), v.clone().into()))
+                .collect(),
+        }
+    }
+}
+
+pub(super) fn write_report(config: &mut GlobalConfig, report: &Report) -> anyhow::Result<()> {
+    let document = JsonReport {
+        cargo_semver_checks_version: crate_version!(),
+        success: report.success(),
+        crates: report
+            .crate_reports()
+            .iter()
+            .map(|(name, crate_report)| (name.as_str(), JsonCrateReport::new(crate_report)))
+            .collect(),
+    };
+
+    let mut stdout = config.stdout();
+    serde_json::to_writer_pretty(&mut stdout, &document)?;
+    writeln!(stdout)?;
+    Ok(())
+}
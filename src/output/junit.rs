@@ -0,0 +1,175 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::time::Duration;
+
+use crate::{CrateReport, GlobalConfig, LintFinding, LintLevel, Report};
+
+/// The outcome of a single lint on a single crate, reported as a JUnit test case.
+enum TestCase<'a> {
+    /// The lint ran and reported nothing.
+    Passed,
+    /// The lint ran and reported results at `warn` level, which don't fail the check.
+    Warned(&'a LintFinding),
+    /// The lint ran and reported results at `deny` level.
+    Failed(&'a LintFinding),
+    /// The lint was not run.
+    Skipped,
+    /// The lint ran, but all its results were allowed by markers in item docs
+    /// or listed as accepted breakage.
+    Allowed,
+}
+
+/// Escapes text for use in XML attribute values and element content.
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Describes each result of the finding on its own line.
+fn finding_details(finding: &LintFinding) -> String {
+    let query = &finding.query;
+    let mut details = String::new();
+    for result in &finding.results {
+        match result.message.as_deref() {
+            Some(message) => details.push_str(message),
+            None => details.push_str(&query.error_message),
+        }
+        details.push('\n');
+    }
+    if let Some(link) = query.reference_link.as_deref() {
+        details.push_str("ref: ");
+        details.push_str(link);
+        details.push('\n');
+    }
+    details
+}
+
+fn write_test_suite(
+    out: &mut String,
+    crate_name: &str,
+    report: &CrateReport,
+) -> anyhow::Result<()> {
+    // Executed and skipped lints, sorted by lint id.
+    let mut test_cases: BTreeMap<&str, TestCase<'_>> = BTreeMap::new();
+    for id in &report.passed_lints {
+        test_cases.insert(id, TestCase::Passed);
+    }
+    for id in &report.skipped_lints {
+        test_cases.insert(id, TestCase::Skipped);
+    }
+    for finding in &report.findings {
+        let id = finding.query.id.as_str();
+        match finding.lint_level {
+            LintLevel::Deny => {
+                test_cases.insert(id, TestCase::Failed(finding));
+            }
+            LintLevel::Warn => {
+                test_cases.insert(id, TestCase::Warned(finding));
+            }
+            // Allowed results come last. Lints whose results were all allowed
+            // are neither passed nor have other findings, so they are reported as allowed.
+            LintLevel::Allow => {
+                test_cases.entry(id).or_insert(TestCase::Allowed);
+            }
+        }
+    }
+
+    let count = |predicate: fn(&TestCase<'_>) -> bool| {
+        test_cases.values().filter(|case| predicate(case)).count()
+    };
+    let failures = count(|case| matches!(case, TestCase::Failed(_)));
+    let skipped = count(|case| matches!(case, TestCase::Skipped | TestCase::Allowed));
+    let total_time: Duration = report.lint_durations.values().sum();
+
+    let crate_name = escape_xml(crate_name);
+    writeln!(
+        out,
+        r#"  <testsuite name="{crate_name}" tests="{}" failures="{failures}" errors="0" skipped="{skipped}" time="{:.3}">"#,
+        test_cases.len(),
+        total_time.as_secs_f64(),
+    )?;
+
+    for (id, test_case) in test_cases {
+        let time = report.lint_durations.get(id).copied().unwrap_or_default();
+        let id = escape_xml(id);
+        write!(
+            out,
+            r#"    <testcase name="{id}" classname="{crate_name}" time="{:.3}""#,
+            time.as_secs_f64(),
+        )?;
+        match test_case {
+            TestCase::Passed => writeln!(out, "/>")?,
+            TestCase::Skipped => {
+                writeln!(out, ">")?;
+                writeln!(
+                    out,
+                    r#"      <skipped message="lint is allowed or not required by the detected version bump"/>"#
+                )?;
+                writeln!(out, "    </testcase>")?;
+            }
+            TestCase::Allowed => {
+                writeln!(out, ">")?;
+                writeln!(
+                    out,
+                    r#"      <skipped message="results are allowed by markers in item docs or accepted as breakage"/>"#
+                )?;
+                writeln!(out, "    </testcase>")?;
+            }
+            TestCase::Failed(finding) => {
+                let query = &finding.query;
+                writeln!(out, ">")?;
+                writeln!(
+                    out,
+                    r#"      <failure message="{}" type="{}">{}</failure>"#,
+                    escape_xml(&query.error_message),
+                    finding.required_update.as_str(),
+                    escape_xml(&finding_details(finding)),
+                )?;
+                writeln!(out, "    </testcase>")?;
+            }
+            TestCase::Warned(finding) => {
+                writeln!(out, ">")?;
+                writeln!(
+                    out,
+                    "      <system-out>warning: {}\n{}</system-out>",
+                    escape_xml(&finding.query.error_message),
+                    escape_xml(&finding_details(finding)),
+                )?;
+                writeln!(out, "    </testcase>")?;
+            }
+        }
+    }
+
+    writeln!(out, "  </testsuite>")?;
+    Ok(())
+}
+
+/// Prints a JUnit XML report with a test suite for each crate,
+/// and a test case for each lint in it.
+///
+/// Lints at `deny` level that reported results are failures,
+/// while lints that weren't run are marked as skipped.
+pub(super) fn write_report(config: &mut GlobalConfig, report: &Report) -> anyhow::Result<()> {
+    let mut suites = String::new();
+    for (crate_name, crate_report) in report.crate_reports() {
+        write_test_suite(&mut suites, crate_name, crate_report)?;
+    }
+
+    let mut stdout = config.stdout();
+    writeln!(stdout, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(stdout, r#"<testsuites name="cargo-semver-checks">"#)?;
+    write!(stdout, "{suites}")?;
+    writeln!(stdout, "</testsuites>")?;
+    Ok(())
+}
@@ -0,0 +1,204 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs::OpenOptions;
+use std::io::Write as _;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use trustfall::TransparentValue;
+
+use crate::{CrateReport, GlobalConfig, LintFinding, LintLevel, Report, WitnessGeneration};
+
+/// Appends a Markdown summary of the report to the file at `path`, creating it if necessary.
+///
+/// The file is appended to rather than overwritten, since `$GITHUB_STEP_SUMMARY`
+/// may already contain summaries written by earlier commands in the same step.
+pub(crate) fn write_summary(
+    config: &GlobalConfig,
+    report: &Report,
+    witness_generation: &WitnessGeneration,
+    path: &Path,
+) -> anyhow::Result<()> {
+    let summary = render_summary(config, report, witness_generation)?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("failed to open Markdown summary file {}", path.display()))?;
+    file.write_all(summary.as_bytes())
+        .with_context(|| format!("failed to write Markdown summary to {}", path.display()))?;
+    Ok(())
+}
+
+fn render_summary(
+    config: &GlobalConfig,
+    report: &Report,
+    witness_generation: &WitnessGeneration,
+) -> anyhow::Result<String> {
+    let mut out = String::new();
+
+    writeln!(out, "## cargo-semver-checks\n")?;
+    writeln!(
+        out,
+        "| Crate | Version (baseline → current) | Detected bump | Required bump | Result |"
+    )?;
+    writeln!(out, "| --- | --- | --- | --- | --- |")?;
+    for (crate_name, crate_report) in report.crate_reports() {
+        writeln!(
+            out,
+            "| `{crate_name}` | {} → {} | {} | {} | {} |",
+            crate_report
+                .baseline_version
+                .as_deref()
+                .unwrap_or("unknown"),
+            crate_report.current_version.as_deref().unwrap_or("unknown"),
+            crate_report.detected_bump.as_str(),
+            crate_report
+                .required_bump
+                .map_or("none", |bump| bump.as_str()),
+            if crate_report.success() {
+                "✅ pass"
+            } else {
+                "❌ fail"
+            },
+        )?;
+    }
+
+    for (crate_name, crate_report) in report.crate_reports() {
+        render_crate(
+            &mut out,
+            config,
+            crate_name,
+            crate_report,
+            witness_generation,
+        )?;
+    }
+
+    Ok(out)
+}
+
+fn render_crate(
+    out: &mut String,
+    config: &GlobalConfig,
+    crate_name: &str,
+    crate_report: &CrateReport,
+    witness_generation: &WitnessGeneration,
+) -> anyhow::Result<()> {
+    // Results allowed by markers in item docs or accepted as breakage are not rendered.
+    if crate_report
+        .findings
+        .iter()
+        .all(|finding| finding.lint_level == LintLevel::Allow)
+    {
+        return Ok(());
+    }
+
+    writeln!(out, "\n### `{crate_name}`")?;
+    for finding in &crate_report.findings {
+        render_finding(out, config, finding, witness_generation)?;
+    }
+    Ok(())
+}
+
+fn render_finding(
+    out: &mut String,
+    config: &GlobalConfig,
+    finding: &LintFinding,
+    witness_generation: &WitnessGeneration,
+) -> anyhow::Result<()> {
+    let query = &finding.query;
+    let icon = match finding.lint_level {
+        LintLevel::Deny => "❌",
+        LintLevel::Warn => "⚠️",
+        LintLevel::Allow => return Ok(()),
+    };
+
+    writeln!(
+        out,
+        "\n#### {icon} {}\n",
+        escape_text(&query.human_readable_name)
+    )?;
+    write!(
+        out,
+        "Lint `{}` ({}, requires {} bump)",
+        query.id,
+        finding.lint_level.as_str(),
+        finding.required_update.as_str(),
+    )?;
+    match query.reference_link.as_deref() {
+        Some(link) => writeln!(
+            out,
+            ": {} [Reference]({link})\n",
+            escape_text(&query.error_message)
+        )?,
+        None => writeln!(out, ": {}\n", escape_text(&query.error_message))?,
+    }
+
+    for result in &finding.results {
+        let pretty_result: BTreeMap<&Arc<str>, TransparentValue> = result
+            .values
+            .iter()
+            .map(|(k, v)| (k, v.clone().into()))
+            .collect();
+
+        match result.message.as_deref() {
+            Some(message) => writeln!(out, "- {}", escape_text(message))?,
+            None => writeln!(out, "- `{}`", serde_json::to_string(&pretty_result)?)?,
+        }
+
+        if let Some(witness) = &query.witness {
+            if witness_generation.show_hints {
+                let hint = config
+                    .handlebars()
+                    .render_template(&witness.hint_template, &pretty_result)
+                    .context("Error instantiating witness hint template.")?;
+
+                writeln!(out, "\n  <details>")?;
+                writeln!(
+                    out,
+                    "  <summary>Downstream code similar to the following would break</summary>\n"
+                )?;
+                writeln!(out, "  ```rust")?;
+                for line in hint.lines() {
+                    writeln!(out, "  {line}")?;
+                }
+                writeln!(out, "  ```\n")?;
+                writeln!(out, "  </details>\n")?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Escapes text so it renders literally in GitHub-flavored Markdown.
+///
+/// Messages routinely mention generic types such as `Vec<T>`,
+/// which would otherwise be parsed as HTML tags and disappear.
+/// `&` is left alone: messages rendered from templates are already HTML-escaped,
+/// and escaping them again would show entities such as `&lt;` verbatim.
+fn escape_text(text: &str) -> String {
+    text.replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('|', "\\|")
+        .replace('\n', "<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::escape_text;
+
+    #[test]
+    fn escapes_html_and_table_syntax() {
+        assert_eq!(
+            escape_text("impl From<u8> for Vec<T> | &str"),
+            "impl From&lt;u8&gt; for Vec&lt;T&gt; \\| &str"
+        );
+        assert_eq!(
+            escape_text("returns Vec&lt;T&gt;\nin src/lib.rs:1"),
+            "returns Vec&lt;T&gt;<br>in src/lib.rs:1"
+        );
+    }
+}
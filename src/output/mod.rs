@@ -0,0 +1,109 @@
+mod github_actions;
+mod json;
+mod junit;
+mod markdown;
+mod sarif;
+
+use std::path::PathBuf;
+
+use crate::{GlobalConfig, OutputFormat, Report};
+
+pub(crate) use markdown::write_summary as write_markdown_summary;
+
+/// Writes the requested machine-readable form of the report to stdout.
+///
+/// Human-readable output is printed while each crate is being checked,
+/// so there is nothing left to do for it here.
+///
+/// Results are only attached to files when `source_roots` is known,
+/// i.e. when the current crate was checked from a project directory.
+pub(crate) fn write_report(
+    config: &mut GlobalConfig,
+    output_format: OutputFormat,
+    report: &Report,
+    source_roots: Option<&SourceRoots>,
+) -> anyhow::Result<()> {
+    match output_format {
+        OutputFormat::Human => Ok(()),
+        OutputFormat::Json => json::write_report(config, report),
+        OutputFormat::Sarif => sarif::write_report(config, report, source_roots),
+        OutputFormat::GithubActions => github_actions::write_report(config, report, source_roots),
+        OutputFormat::Junit => junit::write_report(config, report),
+    }
+}
+
+/// Where the files referenced by lint result spans live, for output formats
+/// that attach results to files in the repository.
+pub(crate) struct SourceRoots {
+    /// Directory that span paths are made relative to.
+    workspace_root: PathBuf,
+    /// Directories holding sources that aren't part of the repository,
+    /// such as baselines checked out from a git revision.
+    excluded_dirs: Vec<PathBuf>,
+}
+
+impl SourceRoots {
+    pub(crate) fn new(workspace_root: PathBuf, excluded_dirs: Vec<PathBuf>) -> Self {
+        Self {
+            workspace_root,
+            excluded_dirs,
+        }
+    }
+
+    /// Makes a span's filename relative to the workspace root, using `/` as the separator.
+    ///
+    /// Returns `None` if the file is outside the workspace or inside one of the excluded
+    /// directories. Removal lints report spans in the baseline, which may have been
+    /// extracted into the target directory or downloaded from a registry, and pointing
+    /// at such files would refer to paths that don't exist in the repository.
+    fn span_path(&self, filename: &str) -> Option<String> {
+        // Rustdoc records relative paths when the crate was documented from its own directory.
+        let path = self.workspace_root.join(filename);
+        if self
+            .excluded_dirs
+            .iter()
+            .any(|excluded| path.starts_with(excluded))
+        {
+            return None;
+        }
+        let relative = path.strip_prefix(&self.workspace_root).ok()?;
+        Some(relative.to_string_lossy().replace('\\', "/"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::SourceRoots;
+
+    fn source_roots() -> SourceRoots {
+        SourceRoots::new(
+            PathBuf::from("/repo"),
+            vec![PathBuf::from("/repo/target/semver-checks")],
+        )
+    }
+
+    #[test]
+    fn span_path_inside_workspace() {
+        let roots = source_roots();
+        assert_eq!(
+            roots.span_path("/repo/src/lib.rs").as_deref(),
+            Some("src/lib.rs")
+        );
+        assert_eq!(roots.span_path("src/lib.rs").as_deref(), Some("src/lib.rs"));
+    }
+
+    #[test]
+    fn span_path_outside_workspace() {
+        let roots = source_roots();
+        assert_eq!(
+            roots.span_path("/home/user/.cargo/registry/src/foo-1.0.0/src/lib.rs"),
+            None
+        );
+        assert_eq!(
+            roots.span_path("/repo/target/semver-checks/git-v1/foo/src/lib.rs"),
+            None
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::BTreeMap;
+use std::io::Write as _;
+
+use clap::crate_version;
+use serde::Serialize;
+
+use crate::{AllowedBy, GlobalConfig, LintLevel, Report, SemverQuery};
+
+use super::SourceRoots;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+/// The top-level SARIF 2.1.0 document, containing a single run of `cargo-semver-checks`.
+#[derive(Debug, Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct Run<'a> {
+    tool: Tool<'a>,
+    results: Vec<SarifResult<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct Tool<'a> {
+    driver: Driver<'a>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver<'a> {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<Rule<'a>>,
+}
+
+/// A SARIF rule, describing one of our lints.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule<'a> {
+    id: &'a str,
+    name: &'a str,
+    short_description: Message<'a>,
+    full_description: Message<'a>,
+    help: Message<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<&'a str>,
+    default_configuration: RuleConfiguration,
+}
+
+#[derive(Debug, Serialize)]
+struct RuleConfiguration {
+    level: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct Message<'a> {
+    text: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult<'a> {
+    rule_id: &'a str,
+    rule_index: usize,
+    level: &'static str,
+    message: Message<'a>,
+    locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suppressions: Vec<Suppression>,
+    properties: ResultProperties<'a>,
+}
+
+/// Why a result was not reported as a problem.
+#[derive(Debug, Serialize)]
+struct Suppression {
+    /// `inSource` for results allowed by a marker in the item's docs,
+    /// and `external` for results listed in the accepted-breakage file.
+    kind: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Debug, Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: u64,
+}
+
+/// Extra information about a result that SARIF has no dedicated field for.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResultProperties<'a> {
+    /// The name of the crate in which the result was found.
+    #[serde(rename = "crate")]
+    crate_name: &'a str,
+    required_update: &'static str,
+}
+
+fn sarif_level(lint_level: LintLevel) -> &'static str {
+    match lint_level {
+        LintLevel::Deny => "error",
+        LintLevel::Warn => "warning",
+        LintLevel::Allow => "none",
+    }
+}
+
+impl<'a> Rule<'a> {
+    fn new(query: &'a SemverQuery) -> Self {
+        Self {
+            id: &query.id,
+            name: &query.human_readable_name,
+            short_description: Message {
+                text: &query.description,
+            },
+            full_description: Message {
+                text: &query.error_message,
+            },
+            help: Message {
+                text: query.reference.as_deref().unwrap_or(&query.description),
+            },
+            help_uri: query.reference_link.as_deref(),
+            default_configuration: RuleConfiguration {
+                level: sarif_level(query.lint_level),
+            },
+        }
+    }
+}
+
+pub(super) fn write_report(
+    config: &mut GlobalConfig,
+    report: &Report,
+    source_roots: Option<&SourceRoots>,
+) -> anyhow::Result<()> {
+    let queries = SemverQuery::all_queries();
+    let rule_indexes: BTreeMap<&str, usize> = queries
+        .keys()
+        .enumerate()
+        .map(|(index, id)| (id.as_str(), index))
+        .collect();
+
+    let mut results = vec![];
+    for (crate_name, crate_report) in report.crate_reports() {
+        for finding in &crate_report.findings {
+            let query = &finding.query;
+            for result in &finding.results {
+                let locations = result
+                    .span()
+                    .and_then(|(filename, begin_line)| {
+                        Some(Location {
+                            physical_location: PhysicalLocation {
+                                artifact_location: ArtifactLocation {
+                                    uri: source_roots?.span_path(filename)?,
+                                },
+                                region: Region {
+                                    start_line: begin_line,
+                                },
+                            },
+                        })
+                    })
+                    .into_iter()
+                    .collect();
+
+                results.push(SarifResult {
+                    rule_id: &query.id,
+                    rule_index: rule_indexes[query.id.as_str()],
+                    level: sarif_level(finding.lint_level),
+                    message: Message {
+                        text: result.message.as_deref().unwrap_or(&query.error_message),
+                    },
+                    locations,
+                    suppressions: match result.allowed_by {
+                        Some(AllowedBy::Marker) => vec![Suppression { kind: "inSource" }],
+                        Some(AllowedBy::AcceptedBreakage) => {
+                            vec![Suppression { kind: "external" }]
+                        }
+                        None => vec![],
+                    },
+                    properties: ResultProperties {
+                        crate_name,
+                        required_update: finding.required_update.as_str(),
+                    },
+                });
+            }
+        }
+    }
+
+    let document = SarifLog {
+        schema: SARIF_SCHEMA,
+        version: SARIF_VERSION,
+        runs: vec![Run {
+            tool: Tool {
+                driver: Driver {
+                    name: "cargo-semver-checks",
+                    version: crate_version!(),
+                    information_uri: "https://github.com/obi1kenobi/cargo-semver-checks",
+                    rules: queries.values().map(Rule::new).collect(),
+                },
+            },
+            results,
+        }],
+    };
+
+    let mut stdout = config.stdout();
+    serde_json::to_writer_pretty(&mut stdout, &document)?;
+    writeln!(stdout)?;
+    Ok(())
+}
@@ -57,6 +57,57 @@ impl LintLevel {
     }
 }
 
+/// A named set of related lints, which can be configured together.
+///
+/// In lint configuration, a group's name may be used in place of a lint id
+/// to configure all lints in the group at once.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum LintGroup {
+    /// Lints about items becoming `#[deprecated]`.
+    #[serde(alias = "deprecation")]
+    Deprecation,
+    /// Lints about items becoming `#[doc(hidden)]`.
+    #[serde(alias = "doc_hidden")]
+    DocHidden,
+    /// Lints about `#[must_use]` being added or removed.
+    #[serde(alias = "must_use")]
+    MustUse,
+    /// Lints about calling conventions, exported symbols, and `repr` layout guarantees.
+    #[serde(alias = "abi")]
+    Abi,
+    /// Lints about `#[target_feature]` requirements.
+    #[serde(alias = "target_feature")]
+    TargetFeature,
+    /// Lints about `Cargo.toml` manifest changes, such as removed features.
+    #[serde(alias = "manifest")]
+    Manifest,
+}
+
+impl LintGroup {
+    /// All lint groups, in the order they are listed in help output.
+    pub const ALL: [LintGroup; 6] = [
+        Self::Deprecation,
+        Self::DocHidden,
+        Self::MustUse,
+        Self::Abi,
+        Self::TargetFeature,
+        Self::Manifest,
+    ];
+
+    /// The name of the group, as used in lint configuration.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Deprecation => "deprecation",
+            Self::DocHidden => "doc_hidden",
+            Self::MustUse => "must_use",
+            Self::Abi => "abi",
+            Self::TargetFeature => "target_feature",
+            Self::Manifest => "manifest",
+        }
+    }
+}
+
 /// Kind of semver update.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ActualSemverUpdate {
@@ -67,6 +118,15 @@ pub enum ActualSemverUpdate {
 }
 
 impl ActualSemverUpdate {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Major => "major",
+            Self::Minor => "minor",
+            Self::Patch => "patch",
+            Self::NotChanged => "none",
+        }
+    }
+
     pub(crate) fn supports_requirement(&self, required: RequiredSemverUpdate) -> bool {
         match (*self, required) {
             (ActualSemverUpdate::Major, _) => true,
@@ -103,6 +163,11 @@ pub struct SemverQuery {
     /// The default lint level for when this lint occurs.
     pub lint_level: LintLevel,
 
+    /// The groups this lint belongs to, which can be used to configure it
+    /// together with related lints.
+    #[serde(default)]
+    pub groups: Vec<LintGroup>,
+
     #[serde(default)]
     pub reference: Option<String>,
 
@@ -145,7 +210,10 @@ impl SemverQuery {
 
     pub fn all_queries() -> BTreeMap<String, SemverQuery> {
         let mut queries = BTreeMap::default();
-        for (id, query_text) in get_queries() {
+        for (id, query_text) in get_queries()
+            .into_iter()
+            .chain(CODE_CHECKED_LINTS.iter().copied())
+        {
             let query = Self::from_ron_str(query_text).unwrap_or_else(|e| {
                 panic!(
                     "\
@@ -165,7 +233,7 @@ impl SemverQuery {
 }
 
 /// Configured values for a [`SemverQuery`] that differ from the lint's defaults.
-#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct QueryOverride {
     /// The required version bump for this lint; see [`SemverQuery`].`required_update`.
@@ -183,7 +251,8 @@ pub struct QueryOverride {
     pub lint_level: Option<LintLevel>,
 }
 
-/// A mapping of lint ids to configured values that override that lint's defaults.
+/// A mapping of lint ids or [`LintGroup`] names to configured values
+/// that override the defaults of that lint, or of all lints in that group.
 pub type OverrideMap = BTreeMap<String, QueryOverride>;
 
 /// A stack of [`OverrideMap`] values capturing our precedence rules.
@@ -210,27 +279,35 @@ impl OverrideStack {
         self.0.push(item.clone());
     }
 
+    /// Finds the topmost override value for this query, where an override for the query's id
+    /// takes precedence over an override for one of its groups within the same map.
+    fn find_override<T>(
+        &self,
+        query: &SemverQuery,
+        value: impl Fn(&QueryOverride) -> Option<T>,
+    ) -> Option<T> {
+        self.0.iter().rev().find_map(|map| {
+            std::iter::once(query.id.as_str())
+                .chain(query.groups.iter().map(|group| group.as_str()))
+                .find_map(|key| map.get(key).and_then(&value))
+        })
+    }
+
     /// Calculates the *effective* lint level of this query, by searching for an override
-    /// mapped to this query's id from the top of the stack first, returning the query's default
-    /// lint level if not overridden.
+    /// mapped to this query's id or one of its groups from the top of the stack first,
+    /// returning the query's default lint level if not overridden.
     #[must_use]
     pub fn effective_lint_level(&self, query: &SemverQuery) -> LintLevel {
-        self.0
-            .iter()
-            .rev()
-            .find_map(|x| x.get(&query.id).and_then(|y| y.lint_level))
+        self.find_override(query, |x| x.lint_level)
             .unwrap_or(query.lint_level)
     }
 
     /// Calculates the *effective* required version bump of this query, by searching for an override
-    /// mapped to this query's id from the top of the stack first, returning the query's default
-    /// required version bump if not overridden.
+    /// mapped to this query's id or one of its groups from the top of the stack first,
+    /// returning the query's default required version bump if not overridden.
     #[must_use]
     pub fn effective_required_update(&self, query: &SemverQuery) -> RequiredSemverUpdate {
-        self.0
-            .iter()
-            .rev()
-            .find_map(|x| x.get(&query.id).and_then(|y| y.required_update))
+        self.find_override(query, |x| x.required_update)
             .unwrap_or(query.required_update)
     }
 }
@@ -376,8 +453,8 @@ mod tests {
     };
 
     use crate::query::{
-        InheritedValue, LintLevel, OverrideMap, OverrideStack, QueryOverride, RequiredSemverUpdate,
-        SemverQuery,
+        CODE_CHECKED_LINTS, InheritedValue, LintGroup, LintLevel, OverrideMap, OverrideStack,
+        QueryOverride, RequiredSemverUpdate, SemverQuery,
     };
     use crate::templating::make_handlebars_registry;
 
@@ -509,6 +586,12 @@ mod tests {
         let adapter =
             VersionedRustdocAdapter::new(current, Some(current)).expect("failed to create adapter");
         for semver_query in SemverQuery::all_queries().into_values() {
+            if CODE_CHECKED_LINTS
+                .iter()
+                .any(|(id, _)| *id == semver_query.id)
+            {
+                continue;
+            }
             let _ = adapter
                 .run_query(&semver_query.query, semver_query.arguments)
                 .expect("not a valid query");
@@ -1001,6 +1084,7 @@ mod tests {
             error_message: String::new(),
             per_result_error_template: None,
             witness: None,
+            groups: vec![],
         }
     }
 
@@ -1108,6 +1192,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_group_overrides() {
+        let mut stack = OverrideStack::new();
+        stack.push(&OverrideMap::from_iter([
+            (
+                "deprecation".into(),
+                QueryOverride {
+                    lint_level: Some(LintLevel::Allow),
+                    required_update: None,
+                },
+            ),
+            (
+                "query2".into(),
+                QueryOverride {
+                    lint_level: Some(LintLevel::Deny),
+                    required_update: None,
+                },
+            ),
+        ]));
+
+        let mut q1 = make_blank_query(
+            "query1".into(),
+            LintLevel::Warn,
+            RequiredSemverUpdate::Minor,
+        );
+        q1.groups = vec![LintGroup::Deprecation];
+        let mut q2 = make_blank_query(
+            "query2".into(),
+            LintLevel::Warn,
+            RequiredSemverUpdate::Minor,
+        );
+        q2.groups = vec![LintGroup::Deprecation];
+        let q3 = make_blank_query(
+            "query3".into(),
+            LintLevel::Warn,
+            RequiredSemverUpdate::Minor,
+        );
+
+        // Group overrides apply to all lints in the group.
+        assert_eq!(stack.effective_lint_level(&q1), LintLevel::Allow);
+        // An override for the lint itself wins over its group in the same map.
+        assert_eq!(stack.effective_lint_level(&q2), LintLevel::Deny);
+        // Lints outside the group are unaffected.
+        assert_eq!(stack.effective_lint_level(&q3), LintLevel::Warn);
+
+        // A group override higher in the stack wins over a lint override lower in the stack.
+        stack.push(&OverrideMap::from_iter([(
+            "deprecation".into(),
+            QueryOverride {
+                lint_level: Some(LintLevel::Warn),
+                required_update: Some(RequiredSemverUpdate::Major),
+            },
+        )]));
+        assert_eq!(stack.effective_lint_level(&q2), LintLevel::Warn);
+        assert_eq!(
+            stack.effective_required_update(&q2),
+            RequiredSemverUpdate::Major
+        );
+    }
+
+    #[test]
+    fn all_lint_groups_are_used() {
+        let queries = SemverQuery::all_queries();
+        for group in LintGroup::ALL {
+            assert!(
+                queries.values().any(|query| query.groups.contains(&group)),
+                "no lint belongs to group `{}`",
+                group.as_str()
+            );
+        }
+        for query in queries.values() {
+            assert!(
+                LintGroup::ALL
+                    .iter()
+                    .all(|group| group.as_str() != query.id),
+                "lint `{}` has the same name as a lint group",
+                query.id
+            );
+        }
+    }
+
     /// Makes sure we can specify [`InheritedValue`]s with `Inherited(...)`
     /// and untagged variants as [`TransparentValue`]s.
     #[test]
@@ -1185,7 +1350,11 @@ mod tests {
         lints_dir.push("src");
         lints_dir.push("lints");
 
-        let expected_lints: BTreeSet<_> = added_lints.iter().copied().collect();
+        let expected_lints: BTreeSet<_> = added_lints
+            .iter()
+            .copied()
+            .chain(CODE_CHECKED_LINTS.iter().map(|(id, _)| *id))
+            .collect();
         let mut missing_lints: BTreeSet<String> = Default::default();
 
         let dir_contents =
@@ -1349,6 +1518,14 @@ macro_rules! add_lints {
     }
 }
 
+/// Lints whose results are computed in code instead of by running their query,
+/// since the data they need isn't available through the rustdoc adapter.
+/// Their definitions have an empty `query`.
+pub(crate) const CODE_CHECKED_LINTS: &[(&str, &str)] = &[(
+    "rust_version_increased",
+    include_str!("lints/rust_version_increased.ron"),
+)];
+
 // The following add_lints! invocation is programmatically edited by scripts/make_new_lint.sh
 // If you must manually edit it, be sure to read the "Requirements" comments in that script first
 add_lints!(
@@ -1530,4 +1707,23 @@ add_lints!(
     unsafe_function_target_feature_added,
     unsafe_inherent_method_target_feature_added,
     unsafe_trait_method_target_feature_added,
+    function_generic_type_param_bound_added,
+    inherent_method_generic_type_param_bound_added,
+    trait_method_generic_type_param_bound_added,
+    type_generic_type_param_bound_added,
+    trait_generic_type_param_bound_added,
+    trait_impl_removed,
+    pub_const_value_changed,
+    pub_const_literal_changed,
+    struct_pub_field_type_changed,
+    enum_tuple_variant_field_type_changed,
+    enum_struct_variant_field_type_changed,
+    function_return_impl_send_removed,
+    inherent_method_return_impl_send_removed,
+    inherent_impl_generic_type_param_bound_added,
+    trait_impl_generic_type_param_bound_added,
+    function_return_impl_sync_removed,
+    function_return_impl_unpin_removed,
+    inherent_method_return_impl_sync_removed,
+    inherent_method_return_impl_unpin_removed,
 );
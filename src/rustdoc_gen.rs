@@ -6,10 +6,9 @@ use anyhow::{Context as _, bail};
 use itertools::Itertools;
 use serde::Serialize;
 use tame_index::IndexKrate;
-use trustfall_rustdoc::VersionedStorage;
 
 use crate::GlobalConfig;
-use crate::data_generation::{CrateDataRequest, IntoTerminalResult as _, TerminalError};
+use crate::data_generation::{CrateData, CrateDataRequest, IntoTerminalResult as _, TerminalError};
 use crate::manifest::Manifest;
 
 #[derive(Debug, Clone)]
@@ -315,7 +314,7 @@ pub(crate) fn generate_rustdoc(
     cache_settings: super::data_generation::CacheSettings<()>,
     target_root: PathBuf,
     data_request: &CrateDataRequest<'_>,
-) -> Result<VersionedStorage, TerminalError> {
+) -> Result<CrateData, TerminalError> {
     let cache_dir = target_root.join("cache");
     let cache_settings = cache_settings.with_path(cache_dir.as_path());
 
@@ -496,13 +495,13 @@ impl<'a> StatefulRustdocGenerator<'a, ReadyState<'a>> {
         }
     }
 
-    /// Load rustdoc from this generator into a [`VersionedStorage`]
+    /// Load rustdoc from this generator into a [`CrateData`]
     pub(crate) fn load_rustdoc(
         &self,
         config: &mut GlobalConfig,
         generation_settings: super::data_generation::GenerationSettings,
         cache_settings: super::data_generation::CacheSettings<()>,
-    ) -> Result<VersionedStorage, TerminalError> {
+    ) -> Result<CrateData, TerminalError> {
         match &self.coupled_state {
             ReadyState::File { generator } => generator.load_rustdoc(),
 
@@ -530,10 +529,15 @@ impl RustdocFromFile {
         Self { path }
     }
 
-    pub(crate) fn load_rustdoc(&self) -> Result<VersionedStorage, TerminalError> {
-        trustfall_rustdoc::load_rustdoc(&self.path, None)
+    pub(crate) fn load_rustdoc(&self) -> Result<CrateData, TerminalError> {
+        let storage = trustfall_rustdoc::load_rustdoc(&self.path, None)
             .with_context(|| format!("failed to load rustdoc from file at `{:?}`", self.path))
-            .into_terminal_result()
+            .into_terminal_result()?;
+        // Without package metadata, there is no `rust-version` to check.
+        Ok(CrateData {
+            storage,
+            rust_version: None,
+        })
     }
 }
 
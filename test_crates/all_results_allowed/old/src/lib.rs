@@ -0,0 +1,8 @@
+#![no_std]
+
+/// Removing this function is allowed by the marker in its docs.
+///
+/// semver-checks: allow(function_missing)
+pub fn allowed_removed_fn() {}
+
+pub fn kept_fn() {}
@@ -0,0 +1,11 @@
+pub enum Enum {
+    TypeChanged(u64, String),
+    // Inserting or removing fields shifts the types of later fields.
+    // This is reported by `enum_tuple_variant_field_added` and
+    // `enum_tuple_variant_field_missing`, not as changed field types.
+    FieldInsertedAtStart(bool, u32, String),
+    FieldInsertedInMiddle(u32, bool, String),
+    FieldRemovedAtStart(String),
+    FieldRemovedInMiddle(u32, bool),
+    Unchanged(u32, String),
+}
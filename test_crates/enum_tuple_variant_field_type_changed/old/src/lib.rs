@@ -0,0 +1,8 @@
+pub enum Enum {
+    TypeChanged(u32, String),
+    FieldInsertedAtStart(u32, String),
+    FieldInsertedInMiddle(u32, String),
+    FieldRemovedAtStart(u32, String),
+    FieldRemovedInMiddle(u32, String, bool),
+    Unchanged(u32, String),
+}
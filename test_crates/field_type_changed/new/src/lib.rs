@@ -0,0 +1,38 @@
+pub struct PlainStruct {
+    pub changed: u64,
+    pub unchanged: u32,
+    pub generic_arg_changed: Option<u16>,
+    /// Not public, so its type may change.
+    private: u64,
+}
+
+pub struct TupleStruct(pub u32, pub i32);
+
+/// Not public, so its fields' types may change.
+struct PrivateStruct {
+    pub field: u64,
+}
+
+pub enum PublicEnum {
+    Tuple(u32, &'static str),
+    Struct { changed: u64, unchanged: bool },
+    Unit,
+}
+
+/// Not public, so its variants' field types may change.
+enum PrivateEnum {
+    Tuple(u64),
+    Struct { field: u64 },
+}
+
+/// Renaming a generic parameter doesn't change the types of fields that use it.
+pub struct StructRenamedParam<U> {
+    pub value: U,
+}
+
+pub struct TupleStructRenamedParam<U>(pub U);
+
+pub enum EnumRenamedParam<U> {
+    Tuple(U),
+    Struct { value: U },
+}
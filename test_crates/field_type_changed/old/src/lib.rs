@@ -0,0 +1,38 @@
+pub struct PlainStruct {
+    pub changed: u32,
+    pub unchanged: u32,
+    pub generic_arg_changed: Option<u8>,
+    /// Not public, so its type may change.
+    private: u32,
+}
+
+pub struct TupleStruct(pub u32, pub i64);
+
+/// Not public, so its fields' types may change.
+struct PrivateStruct {
+    pub field: u32,
+}
+
+pub enum PublicEnum {
+    Tuple(u32, String),
+    Struct { changed: u32, unchanged: bool },
+    Unit,
+}
+
+/// Not public, so its variants' field types may change.
+enum PrivateEnum {
+    Tuple(u32),
+    Struct { field: u32 },
+}
+
+/// Renaming a generic parameter doesn't change the types of fields that use it.
+pub struct StructRenamedParam<T> {
+    pub value: T,
+}
+
+pub struct TupleStructRenamedParam<T>(pub T);
+
+pub enum EnumRenamedParam<T> {
+    Tuple(T),
+    Struct { value: T },
+}
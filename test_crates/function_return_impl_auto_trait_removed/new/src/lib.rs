@@ -0,0 +1,48 @@
+pub fn send_removed() -> impl Iterator<Item = u8> {
+    core::iter::empty()
+}
+
+pub fn send_kept() -> impl Iterator<Item = u8> + Send {
+    core::iter::empty()
+}
+
+/// Only `Sync` is removed here, while `Send` is kept.
+pub fn sync_removed() -> impl Iterator<Item = u8> + Send {
+    core::iter::empty()
+}
+
+pub fn unpin_removed() -> impl Iterator<Item = u8> {
+    core::iter::empty()
+}
+
+/// Auto traits are mentioned in the return type, but aren't bounds of the `impl Trait`.
+pub fn auto_traits_in_item_type() -> impl Iterator<Item = Box<dyn Send + Sync + Unpin>> {
+    core::iter::empty()
+}
+
+/// The return type is no longer opaque, and the concrete type still implements
+/// the same auto traits.
+pub fn becomes_concrete() -> core::iter::Empty<u8> {
+    core::iter::empty()
+}
+
+pub struct Type;
+
+impl Type {
+    pub fn send_removed(&self) -> impl Iterator<Item = u8> {
+        core::iter::empty()
+    }
+
+    pub fn sync_removed(&self) -> impl Iterator<Item = u8> {
+        core::iter::empty()
+    }
+
+    pub fn unpin_removed(&self) -> impl Iterator<Item = u8> {
+        core::iter::empty()
+    }
+}
+
+/// Not public, so changing its return type is not breaking.
+fn private_send_removed() -> impl Iterator<Item = u8> {
+    core::iter::empty()
+}
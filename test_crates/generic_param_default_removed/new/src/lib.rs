@@ -0,0 +1,39 @@
+// Removing the default of a generic parameter makes it required. This is reported by
+// `type_requires_more_generic_type_params`, `type_requires_more_const_generic_params`,
+// and their `trait_*` equivalents.
+
+use std::marker::PhantomData;
+
+pub struct StructDefaultRemoved<T>(PhantomData<T>);
+
+pub struct StructDefaultKept<T = u8>(PhantomData<T>);
+
+/// The parameter is renamed, but it still has a default. This is not breaking.
+pub struct StructDefaultRenamed<U = u8>(PhantomData<U>);
+
+pub enum EnumDefaultRemoved<A, B> {
+    First(A),
+    Second(B),
+}
+
+pub union UnionDefaultRemoved<T: Copy> {
+    value: T,
+}
+
+pub struct StructConstDefaultRemoved<const N: usize>;
+
+pub enum EnumConstDefaultRemoved<T, const N: usize> {
+    Array([T; N]),
+}
+
+pub trait TraitDefaultRemoved<T> {}
+
+pub trait TraitDefaultKept<T = u8> {}
+
+pub trait TraitConstDefaultRemoved<const N: usize> {}
+
+/// Not public, so removing defaults is not breaking.
+struct PrivateStruct<T>(PhantomData<T>);
+
+/// Not public, so removing defaults is not breaking.
+trait PrivateTrait<T> {}
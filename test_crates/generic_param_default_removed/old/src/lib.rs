@@ -0,0 +1,39 @@
+// Removing the default of a generic parameter makes it required. This is reported by
+// `type_requires_more_generic_type_params`, `type_requires_more_const_generic_params`,
+// and their `trait_*` equivalents.
+
+use std::marker::PhantomData;
+
+pub struct StructDefaultRemoved<T = u8>(PhantomData<T>);
+
+pub struct StructDefaultKept<T = u8>(PhantomData<T>);
+
+/// The parameter is renamed, but it still has a default. This is not breaking.
+pub struct StructDefaultRenamed<T = u8>(PhantomData<T>);
+
+pub enum EnumDefaultRemoved<A, B = u8> {
+    First(A),
+    Second(B),
+}
+
+pub union UnionDefaultRemoved<T: Copy = u8> {
+    value: T,
+}
+
+pub struct StructConstDefaultRemoved<const N: usize = 4>;
+
+pub enum EnumConstDefaultRemoved<T, const N: usize = 4> {
+    Array([T; N]),
+}
+
+pub trait TraitDefaultRemoved<T = u8> {}
+
+pub trait TraitDefaultKept<T = u8> {}
+
+pub trait TraitConstDefaultRemoved<const N: usize = 4> {}
+
+/// Not public, so removing defaults is not breaking.
+struct PrivateStruct<T = u8>(PhantomData<T>);
+
+/// Not public, so removing defaults is not breaking.
+trait PrivateTrait<T = u8> {}
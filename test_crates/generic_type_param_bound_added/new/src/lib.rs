@@ -0,0 +1,223 @@
+#![no_std]
+
+// Functions
+
+pub fn gains_bound<T: Clone>(value: T) -> T {
+    value
+}
+
+pub fn gains_where_clause<T>(value: T) -> T
+where
+    T: Send,
+{
+    value
+}
+
+pub fn gains_second_bound<T: Clone + Send>(value: T) -> T {
+    value
+}
+
+// Renaming a generic parameter is not a breaking change.
+pub fn renamed_param_same_bound<U: Clone>(value: U) -> U {
+    value
+}
+
+// Removing bounds is not a breaking change.
+pub fn loses_bound<T: Clone>(value: T) -> T {
+    value
+}
+
+// Generic type parameters are `Sized` by default, so this is not a breaking change.
+#[allow(clippy::needless_maybe_sized)]
+pub fn gains_explicit_sized<T: Sized>(value: T) -> T {
+    value
+}
+
+// `impl Trait` parameters don't have a position to compare them by, so they are not checked.
+pub fn impl_trait_gains_bound(value: impl Clone + Send) -> impl Clone {
+    value
+}
+
+fn private_gains_bound<T: Clone>(value: T) -> T {
+    value
+}
+
+// Not public API, so this should not be reported.
+#[doc(hidden)]
+pub fn hidden_gains_bound<T: Clone>(value: T) -> T {
+    private_gains_bound(value)
+}
+
+// Types
+
+pub struct StructGainsBound<T: Clone>(pub T);
+
+pub enum EnumGainsBound<T: Send> {
+    Value(T),
+}
+
+pub union UnionGainsBound<T: Copy + Send> {
+    pub value: T,
+}
+
+pub struct StructRenamedParam<U: Clone>(pub U);
+
+pub struct StructWithMethods;
+
+impl StructWithMethods {
+    pub fn method_gains_bound<T: Send>(&self, value: T) -> T {
+        value
+    }
+
+    pub fn method_unchanged<U: Clone>(&self, value: U) -> U {
+        value
+    }
+}
+
+// Traits
+
+pub trait TraitGainsBound<T: Send> {}
+
+pub trait TraitWithMethods {
+    fn method_gains_bound<T: Clone>(&self, value: T) -> T;
+
+    fn method_unchanged<U: Clone>(&self, value: U) -> U;
+}
+
+// Bounds implied by supertraits of existing bounds
+
+pub trait Base {}
+
+pub trait Derived: Base {}
+
+pub trait DerivedTwice: Derived {}
+
+// `Base` is a supertrait of `Derived`, so this is not a breaking change.
+pub fn gains_local_supertrait_bound<T: Derived + Base>(value: T) -> T {
+    value
+}
+
+// `Base` is a supertrait of `Derived`, which is a supertrait of `DerivedTwice`.
+pub fn gains_transitive_local_supertrait_bound<T: DerivedTwice + Base>(value: T) -> T {
+    value
+}
+
+pub struct StructGainsLocalSupertraitBound<T: Derived + Base>(pub T);
+
+// `Clone` is a supertrait of `Copy`, and `PartialOrd` of `Ord`, so these are not breaking
+// changes. Rustdoc JSON doesn't include the supertraits of built-in traits,
+// so the lints check for these specific traits instead.
+pub fn gains_supertrait_bound<T: Copy + Clone>(value: T) -> T {
+    value
+}
+
+pub fn gains_direct_supertrait_bound<T: Ord + PartialOrd>(value: T) -> T {
+    value
+}
+
+// Impl blocks
+
+pub struct ImplBlockGainsBound<T>(pub T);
+
+impl<T: Clone> ImplBlockGainsBound<T> {
+    pub fn first(&self) {}
+
+    pub fn second(&self) {}
+}
+
+pub struct ImplBlockGainsSupertraitBound<T>(pub T);
+
+// Not a breaking change, like `gains_supertrait_bound` above.
+impl<T: Copy + Clone> ImplBlockGainsSupertraitBound<T> {
+    pub fn method(&self) {}
+}
+
+// Renaming a generic parameter of an impl block is not a breaking change.
+pub struct ImplBlockRenamedParam<U>(pub U);
+
+impl<U: Clone> ImplBlockRenamedParam<U> {
+    pub fn method(&self) {}
+}
+
+// Also not breaking changes, since `PartialEq` is a supertrait of `Eq`.
+pub fn gains_partial_eq_bound<T: Eq + PartialEq>(value: T) -> T {
+    value
+}
+
+pub struct StructGainsPartialEqBound<T: Eq + PartialEq>(pub T);
+
+// Trait impls
+
+pub trait ImplementedTrait {}
+
+pub struct TraitImplGainsBound<T>(pub T);
+
+impl<T: Clone> ImplementedTrait for TraitImplGainsBound<T> {}
+
+pub struct TraitImplGainsWhereClause<T>(pub T);
+
+impl<T> ImplementedTrait for TraitImplGainsWhereClause<T> where T: Send {}
+
+// Not a breaking change, since `Clone` is a supertrait of `Copy`.
+pub struct TraitImplGainsSupertraitBound<T>(pub T);
+
+impl<T: Copy + Clone> ImplementedTrait for TraitImplGainsSupertraitBound<T> {}
+
+// Renaming a generic parameter of a trait impl is not a breaking change.
+pub struct TraitImplRenamedParam<U>(pub U);
+
+impl<U: Clone> ImplementedTrait for TraitImplRenamedParam<U> {}
+
+// Removing bounds is not a breaking change.
+pub struct TraitImplLosesBound<T>(pub T);
+
+impl<T: Clone> ImplementedTrait for TraitImplLosesBound<T> {}
+
+// Impl blocks matched by their items
+
+pub struct ImplBlockWithoutMethodsGainsBound<T>(pub T);
+
+impl<T: Clone> ImplBlockWithoutMethodsGainsBound<T> {
+    pub const VALUE: usize = 0;
+}
+
+/// Only has private items, so the new bound is not breaking.
+pub struct ImplBlockWithPrivateMethodsGainsBound<T>(pub T);
+
+impl<T: Clone> ImplBlockWithPrivateMethodsGainsBound<T> {
+    fn helper(&self) {}
+}
+
+/// Impl blocks with different bounds, which don't change. Not breaking.
+pub struct SeparateImplBlocks<T>(pub T);
+
+impl<T> SeparateImplBlocks<T> {
+    pub fn unbounded(&self) {}
+}
+
+impl<T: Clone> SeparateImplBlocks<T> {
+    pub fn bounded(&self) {}
+
+    pub const BOUNDED: usize = 0;
+}
+
+/// A new impl block with a bound only has new items. Not breaking.
+pub struct NewBoundedImplBlock<T>(pub T);
+
+impl<T> NewBoundedImplBlock<T> {
+    pub fn existing(&self) {}
+}
+
+impl<T: Clone> NewBoundedImplBlock<T> {
+    pub fn added(&self) {}
+}
+
+pub struct ImplBlockWithPrivateConstGainsBound<T>(pub T);
+
+impl<T: Clone> ImplBlockWithPrivateConstGainsBound<T> {
+    const LIMIT: usize = 1;
+
+    pub fn limit(&self) -> usize {
+        Self::LIMIT
+    }
+}
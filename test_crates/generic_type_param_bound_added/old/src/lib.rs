@@ -0,0 +1,199 @@
+#![no_std]
+
+// Functions
+
+pub fn gains_bound<T>(value: T) -> T {
+    value
+}
+
+pub fn gains_where_clause<T>(value: T) -> T {
+    value
+}
+
+pub fn gains_second_bound<T: Clone>(value: T) -> T {
+    value
+}
+
+pub fn renamed_param_same_bound<T: Clone>(value: T) -> T {
+    value
+}
+
+pub fn loses_bound<T: Clone + Send>(value: T) -> T {
+    value
+}
+
+pub fn gains_explicit_sized<T>(value: T) -> T {
+    value
+}
+
+pub fn impl_trait_gains_bound(value: impl Clone) -> impl Clone {
+    value
+}
+
+fn private_gains_bound<T>(value: T) -> T {
+    value
+}
+
+#[doc(hidden)]
+pub fn hidden_gains_bound<T>(value: T) -> T {
+    private_gains_bound(value)
+}
+
+// Types
+
+pub struct StructGainsBound<T>(pub T);
+
+pub enum EnumGainsBound<T> {
+    Value(T),
+}
+
+pub union UnionGainsBound<T: Copy> {
+    pub value: T,
+}
+
+pub struct StructRenamedParam<T: Clone>(pub T);
+
+pub struct StructWithMethods;
+
+impl StructWithMethods {
+    pub fn method_gains_bound<T>(&self, value: T) -> T {
+        value
+    }
+
+    pub fn method_unchanged<T: Clone>(&self, value: T) -> T {
+        value
+    }
+}
+
+// Traits
+
+pub trait TraitGainsBound<T> {}
+
+pub trait TraitWithMethods {
+    fn method_gains_bound<T>(&self, value: T) -> T;
+
+    fn method_unchanged<T: Clone>(&self, value: T) -> T;
+}
+
+// Bounds implied by supertraits of existing bounds
+
+pub trait Base {}
+
+pub trait Derived: Base {}
+
+pub trait DerivedTwice: Derived {}
+
+pub fn gains_local_supertrait_bound<T: Derived>(value: T) -> T {
+    value
+}
+
+pub fn gains_transitive_local_supertrait_bound<T: DerivedTwice>(value: T) -> T {
+    value
+}
+
+pub struct StructGainsLocalSupertraitBound<T: Derived>(pub T);
+
+pub fn gains_supertrait_bound<T: Copy>(value: T) -> T {
+    value
+}
+
+pub fn gains_direct_supertrait_bound<T: Ord>(value: T) -> T {
+    value
+}
+
+// Impl blocks
+
+pub struct ImplBlockGainsBound<T>(pub T);
+
+impl<T> ImplBlockGainsBound<T> {
+    pub fn first(&self) {}
+
+    pub fn second(&self) {}
+}
+
+pub struct ImplBlockGainsSupertraitBound<T>(pub T);
+
+impl<T: Copy> ImplBlockGainsSupertraitBound<T> {
+    pub fn method(&self) {}
+}
+
+pub struct ImplBlockRenamedParam<T>(pub T);
+
+impl<T: Clone> ImplBlockRenamedParam<T> {
+    pub fn method(&self) {}
+}
+
+pub fn gains_partial_eq_bound<T: Eq>(value: T) -> T {
+    value
+}
+
+pub struct StructGainsPartialEqBound<T: Eq>(pub T);
+
+// Trait impls
+
+pub trait ImplementedTrait {}
+
+pub struct TraitImplGainsBound<T>(pub T);
+
+impl<T> ImplementedTrait for TraitImplGainsBound<T> {}
+
+pub struct TraitImplGainsWhereClause<T>(pub T);
+
+impl<T> ImplementedTrait for TraitImplGainsWhereClause<T> {}
+
+pub struct TraitImplGainsSupertraitBound<T>(pub T);
+
+impl<T: Copy> ImplementedTrait for TraitImplGainsSupertraitBound<T> {}
+
+pub struct TraitImplRenamedParam<T>(pub T);
+
+impl<T: Clone> ImplementedTrait for TraitImplRenamedParam<T> {}
+
+pub struct TraitImplLosesBound<T>(pub T);
+
+impl<T: Clone + Send> ImplementedTrait for TraitImplLosesBound<T> {}
+
+// Impl blocks matched by their items
+
+pub struct ImplBlockWithoutMethodsGainsBound<T>(pub T);
+
+impl<T> ImplBlockWithoutMethodsGainsBound<T> {
+    pub const VALUE: usize = 0;
+}
+
+/// Only has private items, so the new bound is not breaking.
+pub struct ImplBlockWithPrivateMethodsGainsBound<T>(pub T);
+
+impl<T> ImplBlockWithPrivateMethodsGainsBound<T> {
+    fn helper(&self) {}
+}
+
+/// Impl blocks with different bounds, which don't change. Not breaking.
+pub struct SeparateImplBlocks<T>(pub T);
+
+impl<T> SeparateImplBlocks<T> {
+    pub fn unbounded(&self) {}
+}
+
+impl<T: Clone> SeparateImplBlocks<T> {
+    pub fn bounded(&self) {}
+
+    pub const BOUNDED: usize = 0;
+}
+
+/// A new impl block with a bound only has new items. Not breaking.
+pub struct NewBoundedImplBlock<T>(pub T);
+
+impl<T> NewBoundedImplBlock<T> {
+    pub fn existing(&self) {}
+}
+
+pub struct ImplBlockWithPrivateConstGainsBound<T>(pub T);
+
+impl<T> ImplBlockWithPrivateConstGainsBound<T> {
+    const LIMIT: usize = 1;
+
+    pub fn limit(&self) -> usize {
+        Self::LIMIT
+    }
+}
@@ -0,0 +1,14 @@
+#![no_std]
+
+// The marker on the enum allows adding variants to it.
+#[cfg_attr(doc, doc = "semver-checks: allow(enum_variant_added)")]
+pub enum AllowedEnum {
+    First,
+    Second,
+}
+
+/// Adding a variant to this enum should be reported.
+pub enum Enum {
+    First,
+    Second,
+}
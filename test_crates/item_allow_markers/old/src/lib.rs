@@ -0,0 +1,19 @@
+#![no_std]
+
+/// Removing this function is allowed by the marker in its docs.
+///
+/// semver-checks: allow(function_missing)
+pub fn allowed_removed_fn() {}
+
+/// This marker is for a different lint, so removing this function should be reported.
+///
+/// semver-checks: allow(enum_variant_added)
+pub fn removed_fn() {}
+
+pub enum AllowedEnum {
+    First,
+}
+
+pub enum Enum {
+    First,
+}
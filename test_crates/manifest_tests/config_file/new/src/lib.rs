@@ -0,0 +1,9 @@
+#![no_std]
+
+// removing this line should trigger the `function_missing` lint,
+// which is configured in `semver-checks.toml`.
+// pub fn function_missing() {}
+
+// removing this line should similarly trigger `module_missing`,
+// which is configured both in `semver-checks.toml` and in `Cargo.toml`.
+// pub mod module_missing {}
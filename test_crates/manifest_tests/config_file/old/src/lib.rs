@@ -0,0 +1,10 @@
+#![no_std]
+
+// this line will be removed in the `new` version in order to trigger
+// the `function_missing` lint, so we can test that it can be configured
+// in a `semver-checks.toml` file.
+pub fn function_missing() {}
+
+// similarly, this line will be commented out in the `new` version so we
+// can test that manifest configuration takes precedence over the config file.
+pub mod module_missing {}
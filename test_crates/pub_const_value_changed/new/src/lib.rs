@@ -0,0 +1,20 @@
+pub const MAX_LEN: usize = 32;
+
+pub const COMPUTED: u32 = 2 * 16;
+
+pub const NAME: &str = "new";
+
+pub const UNCHANGED: i64 = -1;
+
+/// The value is the same, even though the expression producing it changed.
+pub const SAME_VALUE: u8 = 2 + 2;
+
+/// Not public, so changing the value is not a concern.
+const PRIVATE: u32 = 2;
+
+mod private {
+    /// Not importable, so changing the value is not a concern.
+    pub const HIDDEN_VALUE: u32 = 2;
+}
+
+pub const MARKUP: &str = "<b> & <i>";
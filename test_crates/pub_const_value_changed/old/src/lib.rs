@@ -0,0 +1,20 @@
+pub const MAX_LEN: usize = 64;
+
+pub const COMPUTED: u32 = 2 * 8;
+
+pub const NAME: &str = "old";
+
+pub const UNCHANGED: i64 = -1;
+
+/// The value is the same, even though the expression producing it changed.
+pub const SAME_VALUE: u8 = 4;
+
+/// Not public, so changing the value is not a concern.
+const PRIVATE: u32 = 1;
+
+mod private {
+    /// Not importable, so changing the value is not a concern.
+    pub const HIDDEN_VALUE: u32 = 1;
+}
+
+pub const MARKUP: &str = "<b>";
@@ -0,0 +1,48 @@
+pub trait LocalTrait {}
+
+pub trait BlanketTarget {}
+
+impl<T: Send> BlanketTarget for T {}
+
+trait PrivateTrait {}
+
+/// Manual trait impls for this type are removed in the new version.
+pub struct Removed;
+
+pub struct Counter(pub u32);
+
+/// The concrete impl here is replaced by a blanket impl in the new version.
+pub struct CoveredByBlanket;
+
+/// The concrete impl here becomes generic in the new version.
+pub struct BecomesGeneric;
+
+impl<T: Into<u16>> From<T> for BecomesGeneric {
+    fn from(_: T) -> Self {
+        BecomesGeneric
+    }
+}
+
+/// Derived impls are covered by `derive_trait_impl_removed` instead.
+pub struct DeriveRemoved;
+
+/// Removing the `Clone` impl also removes the blanket `ToOwned` impl,
+/// which should not be reported separately.
+pub struct ManualClone;
+
+/// Not public, so removing its impls is not breaking.
+struct PrivateType;
+
+/// Removing or renaming a trait also removes its impls.
+/// That is reported by `trait_missing` instead.
+pub trait NewlyNamedTrait {}
+
+mod private {
+    /// Public, but not importable from outside the crate.
+    #[allow(dead_code)]
+    pub trait NotImportable {}
+}
+
+pub struct ImplementsChangedTraits;
+
+impl NewlyNamedTrait for ImplementsChangedTraits {}
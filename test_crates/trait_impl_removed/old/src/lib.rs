@@ -0,0 +1,93 @@
+use std::fmt;
+
+pub trait LocalTrait {}
+
+pub trait BlanketTarget {}
+
+trait PrivateTrait {}
+
+/// Manual trait impls for this type are removed in the new version.
+pub struct Removed;
+
+impl fmt::Display for Removed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "removed")
+    }
+}
+
+impl From<u8> for Removed {
+    fn from(_: u8) -> Self {
+        Removed
+    }
+}
+
+impl LocalTrait for Removed {}
+
+/// Removing an impl of a private trait is not breaking.
+impl PrivateTrait for Removed {}
+
+pub struct Counter(pub u32);
+
+impl Iterator for Counter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.checked_sub(1)
+    }
+}
+
+/// The concrete impl here is replaced by a blanket impl in the new version.
+pub struct CoveredByBlanket;
+
+impl BlanketTarget for CoveredByBlanket {}
+
+/// The concrete impl here becomes generic in the new version.
+pub struct BecomesGeneric;
+
+impl From<u16> for BecomesGeneric {
+    fn from(_: u16) -> Self {
+        BecomesGeneric
+    }
+}
+
+/// Derived impls are covered by `derive_trait_impl_removed` instead.
+#[derive(Debug, Clone)]
+pub struct DeriveRemoved;
+
+/// Removing the `Clone` impl also removes the blanket `ToOwned` impl,
+/// which should not be reported separately.
+pub struct ManualClone;
+
+impl Clone for ManualClone {
+    fn clone(&self) -> Self {
+        ManualClone
+    }
+}
+
+/// Not public, so removing its impls is not breaking.
+struct PrivateType;
+
+impl fmt::Display for PrivateType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "private")
+    }
+}
+
+/// Removing or renaming a trait also removes its impls.
+/// That is reported by `trait_missing` instead.
+pub trait RemovedTrait {}
+
+pub trait RenamedTrait {}
+
+mod private {
+    /// Public, but not importable from outside the crate.
+    pub trait NotImportable {}
+}
+
+pub struct ImplementsChangedTraits;
+
+impl RemovedTrait for ImplementsChangedTraits {}
+
+impl RenamedTrait for ImplementsChangedTraits {}
+
+impl private::NotImportable for ImplementsChangedTraits {}
@@ -0,0 +1,137 @@
+use assert_cmd::Command;
+
+/// Runs `cargo semver-checks` on a test crate pair where several traits were removed,
+/// using the given accepted breakage file.
+fn command_with_accepted_breakage(path: &std::path::Path) -> Command {
+    let mut cmd = Command::cargo_bin("cargo-semver-checks")
+        .expect("cargo semver-checks command should exist");
+
+    cmd.env("CARGO_TERM_COLOR", "never")
+        .args([
+            "semver-checks",
+            "--manifest-path",
+            "test_crates/trait_missing/new/",
+            "--baseline-root",
+            "test_crates/trait_missing/old/",
+            "--accepted-breakage",
+        ])
+        .arg(path);
+    cmd
+}
+
+#[test]
+fn accepted_breakage_is_not_reported() {
+    let path = std::env::temp_dir().join(format!(
+        "cargo-semver-checks-accepted-breakage-{}.toml",
+        std::process::id()
+    ));
+
+    // Record all current breakage, which doesn't fail the check.
+    command_with_accepted_breakage(&path)
+        .arg("--write-accepted-breakage")
+        .assert()
+        .success();
+    let contents = std::fs::read_to_string(&path).expect("failed to read accepted breakage");
+    assert!(
+        contents.contains(
+            "[[accepted]]\nlint = \"trait_missing\"\npath = \"trait_missing::RemovedTrait\"\n"
+        ),
+        "{contents}"
+    );
+
+    // With all breakage accepted, the check passes.
+    command_with_accepted_breakage(&path).assert().success();
+
+    // Results that are no longer accepted are reported again.
+    let contents = contents.replace(
+        "[[accepted]]\nlint = \"trait_missing\"\npath = \"trait_missing::RemovedTrait\"\n",
+        "",
+    );
+    std::fs::write(&path, contents).expect("failed to update accepted breakage");
+    let output = command_with_accepted_breakage(&path)
+        .output()
+        .expect("failed to run cargo semver-checks");
+    std::fs::remove_file(&path).expect("failed to remove accepted breakage");
+
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).expect("stdout was not valid UTF-8");
+    assert!(
+        stdout.contains("--- failure trait_missing: pub trait removed or renamed ---"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("trait trait_missing::RemovedTrait,"),
+        "{stdout}"
+    );
+    assert!(
+        !stdout.contains("trait trait_missing::RemovedUnsafeTrait,"),
+        "{stdout}"
+    );
+    assert!(!stdout.contains("--- failure module_missing"), "{stdout}");
+}
+
+#[test]
+fn missing_accepted_breakage_file_is_an_error() {
+    command_with_accepted_breakage(std::path::Path::new("does-not-exist.toml"))
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "failed to read does-not-exist.toml",
+        ));
+}
+
+#[test]
+fn warnings_are_not_written_as_accepted_breakage() {
+    let path = std::env::temp_dir().join(format!(
+        "cargo-semver-checks-accepted-warnings-{}.toml",
+        std::process::id()
+    ));
+
+    command_with_accepted_breakage(&path)
+        .args(["--warn", "trait_missing", "--write-accepted-breakage"])
+        .assert()
+        .success();
+    let contents = std::fs::read_to_string(&path).expect("failed to read accepted breakage");
+    std::fs::remove_file(&path).expect("failed to remove accepted breakage");
+
+    // Only findings that fail the check are recorded.
+    assert!(!contents.contains("lint = \"trait_missing\""), "{contents}");
+    assert!(contents.contains("lint = \"module_missing\""), "{contents}");
+}
+
+#[test]
+fn accepted_breakage_is_suppressed_externally_in_sarif() {
+    let path = std::env::temp_dir().join(format!(
+        "cargo-semver-checks-accepted-sarif-{}.toml",
+        std::process::id()
+    ));
+
+    command_with_accepted_breakage(&path)
+        .arg("--write-accepted-breakage")
+        .assert()
+        .success();
+    let output = command_with_accepted_breakage(&path)
+        .args(["--output-format", "sarif"])
+        .output()
+        .expect("failed to run cargo semver-checks");
+    std::fs::remove_file(&path).expect("failed to remove accepted breakage");
+    assert!(output.status.success());
+
+    let document: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout was not valid JSON");
+    let results = document["runs"][0]["results"]
+        .as_array()
+        .expect("results should be a list");
+    let trait_missing_results: Vec<_> = results
+        .iter()
+        .filter(|result| result["ruleId"] == "trait_missing")
+        .collect();
+    assert!(!trait_missing_results.is_empty(), "{results:#?}");
+    for result in trait_missing_results {
+        assert_eq!(
+            result["suppressions"],
+            serde_json::json!([{ "kind": "external" }]),
+            "{result:#?}"
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use assert_cmd::Command;
+
+/// Returns the names of the items in the results of the lint findings at the given level.
+fn reported_items(crate_report: &serde_json::Value, lint: &str, level: &str) -> Vec<String> {
+    let mut items: Vec<String> = crate_report["findings"]
+        .as_array()
+        .expect("findings should be a list")
+        .iter()
+        .filter(|finding| finding["id"] == lint && finding["lint_level"] == level)
+        .flat_map(|finding| {
+            finding["results"]
+                .as_array()
+                .expect("results should be a list")
+        })
+        .map(|result| {
+            let values = &result["values"];
+            let name = values
+                .get("variant_name")
+                .or_else(|| values.get("name"))
+                .expect("result should name an item");
+            name.as_str().expect("name should be a string").to_string()
+        })
+        .collect();
+    items.sort();
+    items
+}
+
+#[test]
+fn items_marked_in_docs_are_allowed() {
+    let output = Command::cargo_bin("cargo-semver-checks")
+        .expect("cargo semver-checks command should exist")
+        .env("CARGO_TERM_COLOR", "never")
+        .args([
+            "semver-checks",
+            "--manifest-path",
+            "test_crates/item_allow_markers/new/",
+            "--baseline-root",
+            "test_crates/item_allow_markers/old/",
+            "--output-format",
+            "json",
+        ])
+        .output()
+        .expect("failed to run cargo semver-checks");
+    assert!(!output.status.success());
+
+    let document: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout was not valid JSON");
+    let crate_report = &document["crates"]["item_allow_markers"];
+
+    assert_eq!(
+        reported_items(crate_report, "function_missing", "deny"),
+        ["removed_fn"]
+    );
+    assert_eq!(
+        reported_items(crate_report, "function_missing", "allow"),
+        ["allowed_removed_fn"]
+    );
+    assert_eq!(
+        reported_items(crate_report, "enum_variant_added", "deny"),
+        ["Second"]
+    );
+    let allowed_variants = reported_items(crate_report, "enum_variant_added", "allow");
+    assert_eq!(allowed_variants, ["Second"]);
+    let allowed_finding = crate_report["findings"]
+        .as_array()
+        .expect("findings should be a list")
+        .iter()
+        .find(|finding| finding["id"] == "enum_variant_added" && finding["lint_level"] == "allow")
+        .expect("enum_variant_added should have allowed results");
+    assert_eq!(
+        allowed_finding["results"][0]["values"]["enum_name"],
+        "AllowedEnum"
+    );
+
+    let stderr = String::from_utf8(output.stderr).expect("stderr was not valid UTF-8");
+    assert!(
+        stderr.contains("Allowed 1 function_missing results by `semver-checks: allow(...)`"),
+        "{stderr}"
+    );
+}
+
+/// Runs `cargo semver-checks` on a test crate pair whose only breaking change
+/// is allowed by a marker in the docs of the removed item.
+fn command_for_all_results_allowed() -> Command {
+    let mut cmd = Command::cargo_bin("cargo-semver-checks")
+        .expect("cargo semver-checks command should exist");
+
+    cmd.env("CARGO_TERM_COLOR", "never").args([
+        "semver-checks",
+        "--manifest-path",
+        "test_crates/all_results_allowed/new/",
+        "--baseline-root",
+        "test_crates/all_results_allowed/old/",
+    ]);
+    cmd
+}
+
+#[test]
+fn lints_with_only_allowed_results_are_not_counted_as_passing() {
+    let output = command_for_all_results_allowed()
+        .output()
+        .expect("failed to run cargo semver-checks");
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).expect("stderr was not valid UTF-8");
+    let checked = stderr
+        .lines()
+        .find(|line| line.trim_start().starts_with("Checked"))
+        .unwrap_or_else(|| panic!("no summary of the checks: {stderr}"));
+    assert!(checked.contains(" pass, 1 allow, "), "{stderr}");
+}
+
+#[test]
+fn markdown_summary_skips_crates_with_only_allowed_results() {
+    let summary_path = std::env::temp_dir().join(format!(
+        "cargo-semver-checks-allowed-summary-{}.md",
+        std::process::id()
+    ));
+
+    command_for_all_results_allowed()
+        .env("GITHUB_STEP_SUMMARY", &summary_path)
+        .arg("--markdown-summary")
+        .assert()
+        .success();
+
+    let summary = std::fs::read_to_string(&summary_path).expect("failed to read summary");
+    std::fs::remove_file(&summary_path).expect("failed to remove summary");
+
+    assert!(
+        summary.contains("| `all_results_allowed` | 0.1.0 → 0.1.0 |"),
+        "{summary}"
+    );
+    assert!(!summary.contains("### `all_results_allowed`"), "{summary}");
+}
+
+#[test]
+fn junit_reports_lints_with_only_allowed_results_as_skipped() {
+    let output = command_for_all_results_allowed()
+        .args(["--output-format", "junit"])
+        .output()
+        .expect("failed to run cargo semver-checks");
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).expect("stdout was not valid UTF-8");
+    let start = stdout
+        .find("<testcase name=\"function_missing\" classname=\"all_results_allowed\"")
+        .unwrap_or_else(|| panic!("no test case for function_missing: {stdout}"));
+    let test_case = &stdout[start..];
+    let test_case = &test_case[..test_case
+        .find("</testcase>")
+        .unwrap_or_else(|| panic!("function_missing test case was not closed: {stdout}"))];
+    assert!(
+        test_case.contains(r#"<skipped message="results are allowed by markers in item docs or accepted as breakage"/>"#),
+        "{stdout}"
+    );
+}
@@ -0,0 +1,80 @@
+use assert_cmd::Command;
+use predicates::prelude::PredicateBooleanExt;
+
+/// Runs `cargo semver-checks` on a test crate pair where functions were removed,
+/// which only `function_missing` reports, as a major breaking change.
+fn command_for_function_missing() -> Command {
+    let mut cmd = Command::cargo_bin("cargo-semver-checks")
+        .expect("cargo semver-checks command should exist");
+
+    cmd.env("CARGO_TERM_COLOR", "never").args([
+        "semver-checks",
+        "--manifest-path",
+        "test_crates/function_missing/new/",
+        "--baseline-root",
+        "test_crates/function_missing/old/",
+    ]);
+    cmd
+}
+
+#[test]
+fn warn_flag_downgrades_lint() {
+    command_for_function_missing()
+        .args(["--warn", "function_missing"])
+        .assert()
+        .success()
+        .stderr(predicates::str::contains(
+            "Warning produced 1 major and 0 minor level warnings",
+        ));
+}
+
+#[test]
+fn allow_flag_skips_lint() {
+    command_for_function_missing()
+        .args(["--allow", "function_missing"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("function_missing").not());
+}
+
+#[test]
+fn required_update_flag_changes_required_bump() {
+    // The detected bump for this crate pair is minor, so no further bump is required.
+    command_for_function_missing()
+        .args(["--required-update", "function_missing=minor"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn required_update_flag_accepts_comma_separated_list() {
+    command_for_function_missing()
+        .args([
+            "--required-update",
+            "trait_missing=minor,function_missing=minor",
+        ])
+        .assert()
+        .success();
+}
+
+#[test]
+fn unknown_lint_is_error() {
+    command_for_function_missing()
+        .args(["--deny", "no_such_lint"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "unknown lint or lint group `no_such_lint`",
+        ));
+}
+
+#[test]
+fn conflicting_levels_are_error() {
+    command_for_function_missing()
+        .args(["--deny", "function_missing", "--allow", "function_missing"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "lint `function_missing` was given both `--deny` and `--allow`",
+        ));
+}
@@ -1,4 +1,6 @@
-use cargo_semver_checks::{ActualSemverUpdate, Check, GlobalConfig, ReleaseType, Rustdoc};
+use cargo_semver_checks::{
+    ActualSemverUpdate, Check, GlobalConfig, LintLevel, ReleaseType, RequiredSemverUpdate, Rustdoc,
+};
 
 #[test]
 fn major_required_bump_if_breaking_change() {
@@ -32,3 +34,37 @@ fn major_required_bump_if_breaking_change_and_major_bump_detected() {
     assert_eq!(required_bump, None);
     assert_eq!(crate_report.detected_bump(), ActualSemverUpdate::Major);
 }
+
+#[test]
+fn findings_describe_each_triggered_lint() {
+    let current = Rustdoc::from_root("test_crates/trait_missing/new/");
+    let baseline = Rustdoc::from_root("test_crates/trait_missing/old/");
+    let mut check = Check::new(current);
+    let check = check.set_baseline(baseline);
+    let report = check.check_release(&mut GlobalConfig::new()).unwrap();
+    let (_crate_name, crate_report) = report.crate_reports().iter().next().unwrap();
+
+    let finding = crate_report
+        .findings()
+        .iter()
+        .find(|finding| finding.query().id == "trait_missing")
+        .expect("trait_missing should have been triggered");
+    assert_eq!(finding.lint_level(), LintLevel::Deny);
+    assert_eq!(finding.required_update(), RequiredSemverUpdate::Major);
+
+    let result = finding
+        .results()
+        .iter()
+        .find(|result| result.values()["name"].as_str() == Some("RemovedTrait"))
+        .expect("RemovedTrait should have been reported");
+    let message = result
+        .message()
+        .expect("trait_missing has a message template");
+    assert!(
+        message.starts_with("trait trait_missing::RemovedTrait,"),
+        "{message}"
+    );
+    let (filename, begin_line) = result.span().expect("trait_missing reports a span");
+    assert!(filename.ends_with("lib.rs"), "{filename}");
+    assert_eq!(begin_line, 3);
+}
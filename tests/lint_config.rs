@@ -140,3 +140,92 @@ fn test_only_read_config_from_new_manifest() {
         .stderr(predicates::str::is_match("FAIL(.*)struct_missing").expect("regex should be valid"))
         .failure();
 }
+
+/// Tests that lints are configured by a `semver-checks.toml` file at the workspace root,
+/// and that configuration in the manifest takes precedence over it.
+#[test]
+fn test_config_file() {
+    let assert = command_for_crate("config_file").assert();
+    assert
+        .stderr(
+            predicates::str::is_match("WARN(.*)major(.*)function_missing")
+                .expect("regex should be valid"),
+        )
+        .stderr(
+            predicates::str::is_match("WARN(.*)major(.*)module_missing")
+                .expect("regex should be valid"),
+        )
+        .success();
+}
+
+/// Tests that a config file passed with `--config` is used
+/// instead of the `semver-checks.toml` file at the workspace root.
+#[test]
+fn test_explicit_config_file() {
+    let assert = command_for_crate("config_file")
+        .args(["--config", "strict.toml"])
+        .assert();
+    assert
+        .stderr(
+            predicates::str::is_match("FAIL(.*)major(.*)function_missing")
+                .expect("regex should be valid"),
+        )
+        .stderr(
+            predicates::str::is_match("WARN(.*)major(.*)module_missing")
+                .expect("regex should be valid"),
+        )
+        .failure();
+}
+
+/// Tests that a config file also applies when checking rustdoc JSON files directly,
+/// where there is no manifest to read configuration from.
+#[test]
+fn test_config_file_without_manifest() {
+    let mut cmd = Command::cargo_bin("cargo-semver-checks")
+        .expect("cargo semver-checks command should exist");
+    cmd.args([
+        "semver-checks",
+        "--current-rustdoc",
+        "localdata/test_data/function_missing/new/rustdoc.json",
+        "--baseline-rustdoc",
+        "localdata/test_data/function_missing/old/rustdoc.json",
+        "--config",
+        "test_crates/manifest_tests/config_file/new/semver-checks.toml",
+        "-v",
+    ]);
+    cmd.assert()
+        .stderr(
+            predicates::str::is_match("WARN(.*)major(.*)function_missing")
+                .expect("regex should be valid"),
+        )
+        .success();
+}
+
+#[test]
+fn test_rust_version_increased() {
+    let fail = predicates::str::is_match("FAIL(.*)minor(.*)rust_version_increased")
+        .expect("regex should be valid");
+    command_for_crate("rust_version_increased")
+        .assert()
+        .stderr(fail)
+        .stdout(predicates::str::contains(
+            "rust-version increased from 1.70.0 to 1.80.0 in the package's Cargo.toml",
+        ))
+        .failure();
+
+    // The lint is configured like any other lint.
+    let warn = predicates::str::is_match("WARN(.*)minor(.*)rust_version_increased")
+        .expect("regex should be valid");
+    command_for_crate("rust_version_increased")
+        .args(["--warn", "rust_version_increased"])
+        .assert()
+        .stderr(warn)
+        .success();
+
+    // A minor release allows the increase.
+    command_for_crate("rust_version_increased")
+        .args(["--release-type", "minor"])
+        .assert()
+        .stderr(predicates::str::contains("FAIL").not())
+        .success();
+}
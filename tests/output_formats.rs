@@ -0,0 +1,252 @@
+use assert_cmd::Command;
+
+/// Runs `cargo semver-checks` on a test crate pair where three traits were removed,
+/// requesting the given output format.
+fn command_with_output_format(format: &str) -> Command {
+    let mut cmd = Command::cargo_bin("cargo-semver-checks")
+        .expect("cargo semver-checks command should exist");
+
+    cmd.env("CARGO_TERM_COLOR", "never").args([
+        "semver-checks",
+        "--manifest-path",
+        "test_crates/trait_missing/new/",
+        "--baseline-root",
+        "test_crates/trait_missing/old/",
+        "--output-format",
+        format,
+    ]);
+    cmd
+}
+
+#[test]
+fn json_output() {
+    let output = command_with_output_format("json")
+        .output()
+        .expect("failed to run cargo semver-checks");
+    assert!(!output.status.success());
+
+    // stdout must contain nothing but the JSON document.
+    let document: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout was not valid JSON");
+    assert_eq!(document["success"], false);
+
+    let crate_report = &document["crates"]["trait_missing"];
+    assert_eq!(crate_report["success"], false);
+    assert_eq!(crate_report["baseline_version"], "0.1.0");
+    assert_eq!(crate_report["current_version"], "0.1.0");
+    assert_eq!(crate_report["detected_bump"], "minor");
+    assert_eq!(crate_report["required_bump"], "major");
+
+    let findings = crate_report["findings"]
+        .as_array()
+        .expect("findings should be a list");
+    let finding = findings
+        .iter()
+        .find(|finding| finding["id"] == "trait_missing")
+        .expect("trait_missing should have been triggered");
+    assert_eq!(finding["lint_level"], "deny");
+    assert_eq!(finding["required_update"], "major");
+
+    let results = finding["results"]
+        .as_array()
+        .expect("results should be a list");
+    // Three traits were removed, one of which was importable by two paths.
+    assert_eq!(results.len(), 4, "{results:#?}");
+    for result in results {
+        assert!(result["message"].is_string(), "{result:#?}");
+        let filename = result["span"]["filename"]
+            .as_str()
+            .expect("span filename should be a string");
+        assert!(
+            filename.ends_with("trait_missing/old/src/lib.rs"),
+            "{result:#?}"
+        );
+        assert!(result["span"]["begin_line"].is_u64(), "{result:#?}");
+        assert_eq!(result["values"]["span_filename"], filename);
+    }
+
+    let passed_lints = crate_report["passed_lints"]
+        .as_array()
+        .expect("passed_lints should be a list");
+    assert!(passed_lints.iter().any(|id| id == "function_missing"));
+
+    let skipped_lints = crate_report["skipped_lints"]
+        .as_array()
+        .expect("skipped_lints should be a list");
+    // Lints requiring a minor bump are unnecessary, since the detected bump is minor.
+    assert!(
+        skipped_lints
+            .iter()
+            .any(|id| id == "function_must_use_added")
+    );
+}
+
+#[test]
+fn sarif_output() {
+    let output = command_with_output_format("sarif")
+        .output()
+        .expect("failed to run cargo semver-checks");
+    assert!(!output.status.success());
+
+    let document: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout was not valid JSON");
+    assert_eq!(document["version"], "2.1.0");
+
+    let runs = document["runs"].as_array().expect("runs should be a list");
+    assert_eq!(runs.len(), 1);
+    let run = &runs[0];
+    assert_eq!(run["tool"]["driver"]["name"], "cargo-semver-checks");
+
+    let rules = run["tool"]["driver"]["rules"]
+        .as_array()
+        .expect("rules should be a list");
+    let results = run["results"].as_array().expect("results should be a list");
+
+    let trait_missing_results: Vec<_> = results
+        .iter()
+        .filter(|result| result["ruleId"] == "trait_missing")
+        .collect();
+    assert_eq!(trait_missing_results.len(), 4, "{results:#?}");
+    for result in trait_missing_results {
+        assert_eq!(result["level"], "error");
+        assert_eq!(result["properties"]["crate"], "trait_missing");
+
+        let rule_index = result["ruleIndex"]
+            .as_u64()
+            .expect("ruleIndex should be an integer");
+        assert_eq!(rules[rule_index as usize]["id"], "trait_missing");
+
+        // The removed traits are only in the baseline, which is outside the checked workspace.
+        assert_eq!(result["locations"], serde_json::json!([]), "{result:#?}");
+    }
+}
+
+#[test]
+fn github_actions_output() {
+    let output = command_with_output_format("github-actions")
+        .output()
+        .expect("failed to run cargo semver-checks");
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).expect("stdout was not valid UTF-8");
+    let annotations: Vec<_> = stdout
+        .lines()
+        .filter(|line| line.contains("title=trait_missing%3A"))
+        .collect();
+    assert_eq!(annotations.len(), 4, "{stdout}");
+    // The removed traits are only in the baseline, which is outside the checked workspace,
+    // so the annotations aren't attached to a file.
+    // The rendered message refers to the span by its full path, so only check its prefix.
+    assert!(
+        annotations.iter().any(|line| line.starts_with(
+            "::error title=trait_missing%3A pub trait removed or renamed::\
+             trait trait_missing::RemovedTrait, previously in file "
+        )),
+        "{stdout}"
+    );
+    assert!(
+        stdout.lines().all(|line| line.starts_with("::error ")),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn markdown_summary_appended_to_github_step_summary() {
+    let summary_path = std::env::temp_dir().join(format!(
+        "cargo-semver-checks-markdown-summary-{}.md",
+        std::process::id()
+    ));
+    std::fs::write(&summary_path, "# Earlier step output\n").expect("failed to create summary");
+
+    let output = command_with_output_format("human")
+        .env("GITHUB_STEP_SUMMARY", &summary_path)
+        .args([
+            "--markdown-summary",
+            "-Z",
+            "unstable-options",
+            "--witness-hints",
+        ])
+        .output()
+        .expect("failed to run cargo semver-checks");
+    assert!(!output.status.success());
+
+    let summary = std::fs::read_to_string(&summary_path).expect("failed to read summary");
+    std::fs::remove_file(&summary_path).expect("failed to remove summary");
+
+    assert!(summary.starts_with("# Earlier step output\n"), "{summary}");
+    assert!(
+        summary.contains("| `trait_missing` | 0.1.0 → 0.1.0 | minor | major | ❌ fail |"),
+        "{summary}"
+    );
+    assert!(
+        summary.contains("#### ❌ pub trait removed or renamed"),
+        "{summary}"
+    );
+    assert!(
+        summary.contains("- trait trait_missing::RemovedTrait, previously in file "),
+        "{summary}"
+    );
+    assert!(
+        summary.contains("impl trait_missing::TraitBecomesUnsafe for Witness"),
+        "{summary}"
+    );
+}
+
+#[test]
+fn markdown_summary_requires_path_outside_github_actions() {
+    command_with_output_format("human")
+        .env_remove("GITHUB_STEP_SUMMARY")
+        .arg("--markdown-summary")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "the GITHUB_STEP_SUMMARY environment variable is not set",
+        ));
+}
+
+#[test]
+fn junit_output() {
+    let output = command_with_output_format("junit")
+        .output()
+        .expect("failed to run cargo semver-checks");
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).expect("stdout was not valid UTF-8");
+    assert!(
+        stdout.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("<testsuite name=\"trait_missing\""),
+        "{stdout}"
+    );
+    assert_eq!(stdout.matches("<testsuite ").count(), 1, "{stdout}");
+
+    let test_case = |id: &str| {
+        let start = stdout
+            .find(&format!(
+                "<testcase name=\"{id}\" classname=\"trait_missing\""
+            ))
+            .unwrap_or_else(|| panic!("no test case for {id}: {stdout}"));
+        let rest = &stdout[start..];
+        // The test case ends where the next one begins.
+        let end = rest[1..]
+            .find("<testcase ")
+            .map_or(rest.len(), |end| end + 1);
+        rest[..end].to_string()
+    };
+
+    let failed = test_case("trait_missing");
+    assert!(failed.contains("<failure "), "{failed}");
+    assert!(
+        failed.contains("trait trait_missing::RemovedTrait, previously in file "),
+        "{failed}"
+    );
+
+    let passed = test_case("function_missing");
+    assert!(passed.trim_end().ends_with("/>"), "{passed}");
+
+    // Lints requiring a minor bump are unnecessary, since the detected bump is minor.
+    let skipped = test_case("function_must_use_added");
+    assert!(skipped.contains("<skipped "), "{skipped}");
+}